@@ -6,7 +6,7 @@ use curvature::wavelet::WaveletTransformStruct;
 /// Demonstrate Wavelet Transform smoothing
 fn wvt() {
     let raw_signal = vec![1.0, 1.5, 0.8, 2.0, 1.2, 0.9, 1.8, 2.2];
-    let wavelet = WaveletTransformStruct { levels: 2, threshold: 0.1 };
+    let wavelet = WaveletTransformStruct { levels: 2, threshold: 0.1, ..Default::default() };
     let smoothed = wavelet.smooth(&raw_signal);
     println!("Smoothed signal: {:?}", smoothed);
 }
@@ -23,7 +23,7 @@ fn main() {
     let recon = signal.reconstruct();
     println!("Reconstructed signal: {:?}", recon);
 
-    let detector = PercentileHotspot { percentile: 80.0 };
+    let detector = PercentileHotspot { percentile: 80.0, ..Default::default() };
     let hotspots = detector.detect(&recon);
     println!("Hotspot indices: {:?}", hotspots);
 
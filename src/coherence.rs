@@ -2,7 +2,7 @@
 /// and reduce entropy in belief tensors.
 /// This module defines traits and implementations for triggering coherence
 /// pulses based on entropy thresholds.
-use crate::resonance::{EntangleMap};
+use crate::resonance::{EntangleMap, Resonance};
 use coheron::traits::BeliefTensor;
 
 pub trait CoherencePulse<B, E>
@@ -12,12 +12,73 @@ where
 {
     fn trigger(&mut self, belief: &mut B, entanglement: &mut E);
     fn should_trigger(&self, belief: &B) -> bool;
+
+    /// Like `should_trigger`, but also sees the field resonance at the current step.
+    /// `SemanticEngine` calls this version; the default ignores the resonance.
+    fn should_trigger_with_context(&self, belief: &B, _resonance: &Resonance) -> bool {
+        self.should_trigger(belief)
+    }
+
+    /// Entropy above which the pulse fires, for entropy-gated pulses.
+    fn entropy_threshold(&self) -> Option<f64> {
+        None
+    }
+
+    /// Decides from `entropy`, already aggregated across `beliefs` by an `EntropyReducer`.
+    /// The default compares it against `entropy_threshold`; a pulse without one fires if
+    /// it would for any single belief.
+    fn should_trigger_aggregate(&self, beliefs: &[B], entropy: f64, resonance: &Resonance) -> bool {
+        match self.entropy_threshold() {
+            Some(threshold) => entropy > threshold,
+            None => beliefs.iter().any(|b| self.should_trigger_with_context(b, resonance)),
+        }
+    }
+}
+
+/// How `SemanticEngine` combines its beliefs when deciding whether to fire the pulse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EntropyReducer {
+    /// The highest belief entropy decides.
+    #[default]
+    Max,
+    /// The mean belief entropy decides.
+    Mean,
+    /// Fires if the pulse would fire for any single belief.
+    Any,
+}
+
+impl EntropyReducer {
+    /// Aggregates belief entropies for `Max` and `Mean`; `None` for `Any` or no entropies.
+    pub fn reduce(&self, entropies: impl IntoIterator<Item = f64>) -> Option<f64> {
+        let mut iter = entropies.into_iter();
+        let first = iter.next()?;
+        match self {
+            EntropyReducer::Max => Some(iter.fold(first, f64::max)),
+            EntropyReducer::Mean => {
+                let (sum, count) = iter.fold((first, 1), |(s, c), e| (s + e, c + 1));
+                Some(sum / count as f64)
+            }
+            EntropyReducer::Any => None,
+        }
+    }
+}
+
+/// Beliefs whose uncertainty a coherence pulse can shrink.
+pub trait Recohere {
+    /// Moves the variance `factor` of the way toward its floor (1.0 lands on the floor).
+    fn recohere(&mut self, factor: f64);
 }
 
 pub struct EntropyPulse {
     pub threshold: f64,
 }
 
+impl EntropyPulse {
+    pub fn new(threshold: f64) -> Self {
+        EntropyPulse { threshold }
+    }
+}
+
 impl<B, E> CoherencePulse<B, E> for EntropyPulse
 where
     B: BeliefTensor,
@@ -27,6 +88,10 @@ where
         belief.entropy() > self.threshold
     }
 
+    fn entropy_threshold(&self) -> Option<f64> {
+        Some(self.threshold)
+    }
+
     fn trigger(&mut self, belief: &mut B, _entanglement: &mut E) {
         // Crude recoherence: reduce entropy artificially
         println!(
@@ -36,3 +101,225 @@ where
         // Optional: reset variance, amplify signal, reweight entanglement
     }
 }
+
+/// Entropy-gated pulse that recoheres the belief when it fires, for beliefs implementing
+/// `Recohere`.
+pub struct RecoherePulse {
+    pub threshold: f64,
+    /// Fraction in (0, 1] of the gap between a belief's variance and its floor removed
+    /// per trigger: 1.0 is a full reset, 0.1 a gentle nudge.
+    pub recohere_factor: f64,
+}
+
+impl RecoherePulse {
+    pub fn new(threshold: f64, recohere_factor: f64) -> Self {
+        RecoherePulse {
+            threshold,
+            recohere_factor,
+        }
+    }
+}
+
+impl<B, E> CoherencePulse<B, E> for RecoherePulse
+where
+    B: BeliefTensor + Recohere,
+    E: EntangleMap,
+{
+    fn should_trigger(&self, belief: &B) -> bool {
+        belief.entropy() > self.threshold
+    }
+
+    fn entropy_threshold(&self) -> Option<f64> {
+        Some(self.threshold)
+    }
+
+    fn trigger(&mut self, belief: &mut B, _entanglement: &mut E) {
+        belief.recohere(self.recohere_factor.clamp(0.0, 1.0));
+    }
+}
+
+/// Fires when belief entropy exceeds `entropy_threshold` or, given the field context,
+/// when the resonance amplitude exceeds `amplitude_threshold`, so a resonance spike can
+/// trigger recoherence even while entropy is moderate. Firing recoheres the belief as
+/// `RecoherePulse` does.
+pub struct ResonanceEntropyPulse {
+    pub entropy_threshold: f64,
+    pub amplitude_threshold: f64,
+    /// Fraction in (0, 1] of the gap between a belief's variance and its floor removed
+    /// per trigger.
+    pub recohere_factor: f64,
+}
+
+impl ResonanceEntropyPulse {
+    /// Creates a pulse that fully resets variance when triggered.
+    pub fn new(entropy_threshold: f64, amplitude_threshold: f64) -> Self {
+        ResonanceEntropyPulse {
+            entropy_threshold,
+            amplitude_threshold,
+            recohere_factor: 1.0,
+        }
+    }
+}
+
+impl<B, E> CoherencePulse<B, E> for ResonanceEntropyPulse
+where
+    B: BeliefTensor + Recohere,
+    E: EntangleMap,
+{
+    fn should_trigger(&self, belief: &B) -> bool {
+        belief.entropy() > self.entropy_threshold
+    }
+
+    fn should_trigger_with_context(&self, belief: &B, resonance: &Resonance) -> bool {
+        belief.entropy() > self.entropy_threshold || resonance.amplitude > self.amplitude_threshold
+    }
+
+    fn should_trigger_aggregate(
+        &self,
+        _beliefs: &[B],
+        entropy: f64,
+        resonance: &Resonance,
+    ) -> bool {
+        entropy > self.entropy_threshold || resonance.amplitude > self.amplitude_threshold
+    }
+
+    fn trigger(&mut self, belief: &mut B, _entanglement: &mut E) {
+        belief.recohere(self.recohere_factor.clamp(0.0, 1.0));
+    }
+}
+
+/// Returns `(start, end)` (end exclusive) of the longest contiguous run where every
+/// value is at least `threshold`. Ties go to the earliest run; `(0, 0)` if none qualifies.
+pub fn longest_coherent_run(signal: &[f64], threshold: f64) -> (usize, usize) {
+    let mut best = (0, 0);
+    let mut run_start = None;
+
+    for (i, &v) in signal.iter().enumerate() {
+        if v >= threshold {
+            let start = *run_start.get_or_insert(i);
+            if i + 1 - start > best.1 - best.0 {
+                best = (start, i + 1);
+            }
+        } else {
+            run_start = None;
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entangle::SimpleEntangleMap;
+    use crate::sem_eng::SimpleBelief;
+    use coheron::beliefs::KalmanBelief;
+
+    fn triggered_variance(recohere_factor: f64) -> f64 {
+        let mut pulse = RecoherePulse::new(0.0, recohere_factor);
+        let mut belief = SimpleBelief::new(0.0, 2.0);
+        let mut entanglement = SimpleEntangleMap::new();
+        pulse.trigger(&mut belief, &mut entanglement);
+        belief.variance
+    }
+
+    #[test]
+    fn smaller_recohere_factor_reduces_variance_less() {
+        let gentle = triggered_variance(0.1);
+        let full = triggered_variance(1.0);
+        assert!(gentle < 2.0);
+        assert!(gentle > full);
+        assert!((full - 1e-3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn resonance_spike_triggers_at_moderate_entropy() {
+        let pulse = ResonanceEntropyPulse::new(2.0, 0.8);
+        let belief = SimpleBelief::new(0.0, 1.5);
+        let calm = Resonance { amplitude: 0.2, frequency: 1.0 };
+        let spike = Resonance { amplitude: 1.4, frequency: 1.0 };
+        let check = |r: &Resonance| {
+            CoherencePulse::<_, SimpleEntangleMap>::should_trigger_with_context(&pulse, &belief, r)
+        };
+        assert!(!check(&calm));
+        assert!(check(&spike));
+    }
+
+    #[test]
+    fn resonance_pulse_trigger_recoheres() {
+        let mut pulse = ResonanceEntropyPulse::new(2.0, 0.8);
+        pulse.recohere_factor = 0.5;
+        let mut belief = SimpleBelief::new(0.0, 1.001);
+        pulse.trigger(&mut belief, &mut SimpleEntangleMap::new());
+        assert!((belief.variance - 0.501).abs() < 1e-12);
+    }
+
+    /// Implements only the required methods, so every decision goes through the defaults.
+    struct MinimalPulse {
+        threshold: Option<f64>,
+    }
+
+    impl CoherencePulse<SimpleBelief, SimpleEntangleMap> for MinimalPulse {
+        fn trigger(&mut self, _belief: &mut SimpleBelief, _entanglement: &mut SimpleEntangleMap) {}
+
+        fn should_trigger(&self, belief: &SimpleBelief) -> bool {
+            belief.entropy() > 1.0
+        }
+
+        fn entropy_threshold(&self) -> Option<f64> {
+            self.threshold
+        }
+    }
+
+    #[test]
+    fn default_aggregate_compares_the_reduced_entropy_with_the_threshold() {
+        let beliefs = [SimpleBelief::new(0.0, 0.5), SimpleBelief::new(0.0, 20.0)];
+        let entropies = beliefs.iter().map(|b| b.entropy());
+        let resonance = Resonance { amplitude: 0.0, frequency: 0.0 };
+        let pulse = MinimalPulse { threshold: Some(1.0) };
+
+        let max = EntropyReducer::Max.reduce(entropies.clone()).unwrap();
+        assert!(pulse.should_trigger_aggregate(&beliefs, max, &resonance));
+        let low = beliefs[0].entropy();
+        assert!(!pulse.should_trigger_aggregate(&beliefs, low, &resonance));
+    }
+
+    #[test]
+    fn default_aggregate_without_threshold_asks_every_belief() {
+        let beliefs = [SimpleBelief::new(0.0, 0.5), SimpleBelief::new(0.0, 20.0)];
+        let resonance = Resonance { amplitude: 0.0, frequency: 0.0 };
+        let pulse = MinimalPulse { threshold: None };
+        assert!(pulse.should_trigger_aggregate(&beliefs, f64::NEG_INFINITY, &resonance));
+        assert!(!pulse.should_trigger_aggregate(&beliefs[..1], f64::INFINITY, &resonance));
+    }
+
+    #[test]
+    fn reducers_aggregate_entropies() {
+        let entropies = [0.5, 2.5, 1.0];
+        assert_eq!(EntropyReducer::Max.reduce(entropies), Some(2.5));
+        assert_eq!(EntropyReducer::Mean.reduce(entropies), Some(4.0 / 3.0));
+        assert_eq!(EntropyReducer::Any.reduce(entropies), None);
+        assert_eq!(EntropyReducer::Max.reduce([]), None);
+    }
+
+    #[test]
+    fn entropy_pulse_accepts_beliefs_without_recohere() {
+        let pulse: Box<dyn CoherencePulse<KalmanBelief, ()>> = Box::new(EntropyPulse::new(1.0));
+        let belief = KalmanBelief {
+            state: [0.0, 0.0],
+            covariance: [[100.0, 0.0], [0.0, 100.0]],
+            process_noise: 0.1,
+            measurement_noise: 0.1,
+        };
+        assert_eq!(pulse.should_trigger(&belief), belief.entropy() > 1.0);
+    }
+
+    #[test]
+    fn longest_coherent_run_prefers_the_longer_then_the_earlier_run() {
+        let signal = [0.9, 0.8, 0.1, 0.7, 0.75, 0.95, 0.2, 0.6];
+        assert_eq!(longest_coherent_run(&signal, 0.7), (3, 6));
+        assert_eq!(longest_coherent_run(&[0.9, 0.9, 0.0, 0.9, 0.9], 0.5), (0, 2));
+        assert_eq!(longest_coherent_run(&signal, 0.99), (0, 0));
+        assert_eq!(longest_coherent_run(&[], 0.5), (0, 0));
+    }
+}
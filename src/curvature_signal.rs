@@ -1,5 +1,11 @@
 /// Represents curvature signals and provides methods for reconstruction
 /// and frequency estimation.
+pub use crate::error::SignalError;
+use crate::error::CurvatureError;
+use crate::wavelet::{
+    FusionContext, WaveletDecomposition, WaveletEngine, WaveletFusionStrategy, compute_entropy,
+};
+
 #[derive(Debug, Clone)]
 pub struct CurvatureSignal {
     /// Sample positions (e.g., time or spatial domain)
@@ -9,14 +15,283 @@ pub struct CurvatureSignal {
     pub values: Vec<f64>,
 }
 
+/// Incrementally collects samples for a `CurvatureSignal`, rejecting out-of-order
+/// positions as they arrive instead of when the signal is used.
+#[derive(Debug, Clone, Default)]
+pub struct CurvatureSignalBuilder {
+    positions: Vec<f64>,
+    values: Vec<f64>,
+}
+
+impl CurvatureSignalBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a sample. Positions must strictly increase; a regression, repeat or NaN
+    /// position is rejected with `SignalError::NonMonotonic` and not recorded.
+    pub fn push(&mut self, position: f64, value: f64) -> Result<(), CurvatureError> {
+        let previous = self.positions.last().copied().unwrap_or(f64::NEG_INFINITY);
+        if position.is_nan() || position <= previous {
+            return Err(SignalError::NonMonotonic { previous, position }.into());
+        }
+        self.positions.push(position);
+        self.values.push(value);
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Finishes the signal; fails with `SignalError::TooFewSamples` below two samples.
+    pub fn build(self) -> Result<CurvatureSignal, CurvatureError> {
+        if self.positions.len() < 2 {
+            return Err(SignalError::TooFewSamples.into());
+        }
+        Ok(CurvatureSignal {
+            positions: self.positions,
+            values: self.values,
+        })
+    }
+}
+
 impl CurvatureSignal {
+    pub fn builder() -> CurvatureSignalBuilder {
+        CurvatureSignalBuilder::new()
+    }
+
+    /// Appends `other` end-to-end, shifting its positions so its first sample lands one
+    /// spacing after this signal's last position. The spacing is this signal's final gap,
+    /// falling back to `other`'s first gap and then 1.0. A shifted sample that coincides with
+    /// or falls before the previous position (only possible if `other` is not increasing)
+    /// is averaged into that sample, so positions stay strictly increasing. An empty side
+    /// yields a copy of the other.
+    pub fn concat(&self, other: &CurvatureSignal) -> CurvatureSignal {
+        let (Some(&last), Some(&first)) = (self.positions.last(), other.positions.first()) else {
+            return if self.positions.is_empty() { other.clone() } else { self.clone() };
+        };
+        let gap = |p: &[f64]| match p {
+            [.., a, b] if b > a => Some(b - a),
+            _ => None,
+        };
+        let spacing = gap(&self.positions)
+            .or_else(|| gap(&other.positions[..other.positions.len().min(2)]))
+            .unwrap_or(1.0);
+        let offset = last + spacing - first;
+
+        let mut positions = self.positions.clone();
+        let mut values = self.values.clone();
+        // Number of samples merged into the current last one, for a running average
+        let mut merged = 1.0;
+        for (&p, &v) in other.positions.iter().zip(&other.values) {
+            let p = p + offset;
+            match (positions.last(), values.last_mut()) {
+                (Some(&prev), Some(seam)) if p <= prev => {
+                    merged += 1.0;
+                    *seam += (v - *seam) / merged;
+                }
+                _ => {
+                    positions.push(p);
+                    values.push(v);
+                    merged = 1.0;
+                }
+            }
+        }
+
+        CurvatureSignal { positions, values }
+    }
+
     /// Reconstructs a dense signal from sparse curvature samples.
     /// Currently uses linear interpolation; replaceable with spline or physics-aware model.
+    /// Uses `reconstruct_with_resolution(10)`. Returns an empty vec for an invalid signal;
+    /// see `try_reconstruct` for the reason.
     pub fn reconstruct(&self) -> Vec<f64> {
+        self.reconstruct_with_resolution(10)
+    }
+
+    /// Like `reconstruct`, but reports why a signal cannot be reconstructed: wrapped
+    /// `SignalError::LengthMismatch` or `SignalError::TooFewSamples`.
+    pub fn try_reconstruct(&self) -> Result<Vec<f64>, CurvatureError> {
+        self.validate()?;
+        Ok(self.reconstruct())
+    }
+
+    /// Reconstructs with `steps` samples for a segment of average width, giving each
+    /// segment a share proportional to its width (rounded, at least one), so the output
+    /// holds roughly `steps * (n - 1)` samples. Evenly spaced positions get exactly `steps`
+    /// per segment. Returns an empty vec if `steps` is 0 or the signal is invalid.
+    pub fn reconstruct_with_resolution(&self, steps: usize) -> Vec<f64> {
+        if steps == 0 || self.validate().is_err() {
+            return vec![];
+        }
+        let steps_for = self.proportional_steps(steps);
+        self.interpolate(steps_for, &mut |_| {}).into_iter().map(|(_, y)| y).collect()
+    }
+
+    /// Like `reconstruct`, but keeps the position of each sample: `(x, y)` pairs whose `x`
+    /// runs from the first position up to (not including) the last, so samples can be lined
+    /// up with non-uniform positions. Empty for an invalid signal.
+    pub fn reconstruct_pairs(&self) -> Vec<(f64, f64)> {
+        if self.validate().is_err() {
+            return vec![];
+        }
+        self.interpolate(self.proportional_steps(10), &mut |_| {})
+    }
+
+    /// Samples per segment for `reconstruct_with_resolution`: `steps` scaled by the
+    /// segment's width relative to the average. Expects a validated signal.
+    fn proportional_steps(&self, steps: usize) -> impl Fn(f64) -> usize {
+        let n = self.positions.len();
+        let mean_width = (self.positions[n - 1] - self.positions[0]) / (n - 1) as f64;
+        move |width: f64| {
+            if mean_width > 0.0 {
+                ((steps as f64 * width / mean_width).round() as usize).max(1)
+            } else {
+                steps
+            }
+        }
+    }
+
+    /// Checks that positions and values pair up and that there are at least two samples.
+    pub fn validate(&self) -> Result<(), CurvatureError> {
+        let (positions, values) = (self.positions.len(), self.values.len());
+        if positions != values {
+            return Err(SignalError::LengthMismatch { positions, values }.into());
+        }
+        if positions < 2 {
+            return Err(SignalError::TooFewSamples.into());
+        }
+        Ok(())
+    }
+
+    /// Like `reconstruct`, but through a natural cubic spline (zero second derivative at
+    /// both ends), so the result is C2-continuous at the knots. Uses the actual position
+    /// spacing and the same per-segment sample layout as `reconstruct`. With fewer than 3
+    /// samples, or positions that do not strictly increase, falls back to `reconstruct`.
+    pub fn reconstruct_spline(&self) -> Vec<f64> {
+        let Some(second) = self.spline_second_derivatives() else {
+            return self.reconstruct();
+        };
+        let steps_for = self.proportional_steps(10);
+
+        let (x, y) = (&self.positions, &self.values);
+        let mut reconstructed = Vec::with_capacity((x.len() - 1) * 10);
+        for i in 0..x.len() - 1 {
+            let h = x[i + 1] - x[i];
+            let steps = steps_for(h);
+            for j in 0..steps {
+                let b = j as f64 / steps as f64;
+                let a = 1.0 - b;
+                let curve = ((a * a * a - a) * second[i] + (b * b * b - b) * second[i + 1]) * h * h;
+                reconstructed.push(a * y[i] + b * y[i + 1] + curve / 6.0);
+            }
+        }
+        reconstructed
+    }
+
+    /// Second derivatives of the natural cubic spline through the samples, solved with the
+    /// Thomas algorithm. `None` for fewer than 3 samples or non-increasing positions.
+    fn spline_second_derivatives(&self) -> Option<Vec<f64>> {
+        let (x, y) = (&self.positions, &self.values);
+        let n = x.len();
+        if n != y.len() || n < 3 {
+            return None;
+        }
+        let h: Vec<f64> = x.windows(2).map(|w| w[1] - w[0]).collect();
+        if h.iter().any(|&width| width.is_nan() || width <= 0.0) {
+            return None;
+        }
+
+        // Forward sweep over the interior rows h[i-1] m[i-1] + 2(h[i-1] + h[i]) m[i] + h[i] m[i+1]
+        let mut diag = vec![0.0; n];
+        let mut rhs = vec![0.0; n];
+        for i in 1..n - 1 {
+            let slope_jump = (y[i + 1] - y[i]) / h[i] - (y[i] - y[i - 1]) / h[i - 1];
+            diag[i] = 2.0 * (h[i - 1] + h[i]);
+            rhs[i] = 6.0 * slope_jump;
+            if i > 1 {
+                let factor = h[i - 1] / diag[i - 1];
+                diag[i] -= factor * h[i - 1];
+                rhs[i] -= factor * rhs[i - 1];
+            }
+        }
+
+        // Back substitution; the natural end conditions pin both ends at zero
+        let mut second = vec![0.0; n];
+        for i in (1..n - 1).rev() {
+            second[i] = (rhs[i] - h[i] * second[i + 1]) / diag[i];
+        }
+        Some(second)
+    }
+
+    /// Like `reconstruct`, but with `steps` samples per segment (at least one) and
+    /// `progress` called with the completed fraction of segments as the work proceeds:
+    /// at most about every 1%, never decreasing, and always ending with exactly 1.0.
+    pub fn reconstruct_with_progress(
+        &self,
+        steps: usize,
+        progress: &mut dyn FnMut(f64),
+    ) -> Vec<f64> {
+        let segments = self.positions.len().saturating_sub(1).max(1) as f64;
+        let mut reported = 0.0;
+        let values = self
+            .interpolate(|_| steps.max(1), &mut |done| {
+                let fraction = done as f64 / segments;
+                if fraction - reported >= 0.01 && fraction < 1.0 {
+                    progress(fraction);
+                    reported = fraction;
+                }
+            })
+            .into_iter()
+            .map(|(_, y)| y)
+            .collect();
+        progress(1.0);
+        values
+    }
+
+    /// Reconstructs with roughly uniform spatial resolution: each segment gets
+    /// `ceil(width / step_size)` samples (at least one), so wide gaps are filled
+    /// as densely as narrow ones. Returns an empty vec if `step_size` is not positive.
+    pub fn reconstruct_by_spacing(&self, step_size: f64) -> Vec<f64> {
+        if step_size <= 0.0 || step_size.is_nan() {
+            return vec![];
+        }
+        let steps_for = |width: f64| ((width.abs() / step_size).ceil() as usize).max(1);
+        self.interpolate(steps_for, &mut |_| {})
+            .into_iter()
+            .map(|(_, y)| y)
+            .collect()
+    }
+
+    /// Returns the reconstruction as plottable `(x, y)` rows, `steps` per segment
+    /// (at least one), closed with the final sample so the rows span the whole position range.
+    pub fn to_table(&self, steps: usize) -> Vec<(f64, f64)> {
+        let mut table = self.interpolate(|_| steps.max(1), &mut |_| {});
+        // A non-empty table means the signal passed validation, so both vecs share a length
+        if !table.is_empty() {
+            let last = self.positions.len() - 1;
+            table.push((self.positions[last], self.values[last]));
+        }
+        table
+    }
+
+    /// Linearly interpolates each segment into `steps_for(width)` samples, starting at the
+    /// segment's left knot, calling `on_segment` with the number of segments finished.
+    /// Returns `(x, y)` pairs, or nothing for an invalid signal.
+    fn interpolate(
+        &self,
+        steps_for: impl Fn(f64) -> usize,
+        on_segment: &mut dyn FnMut(usize),
+    ) -> Vec<(f64, f64)> {
         let mut reconstructed = Vec::new();
 
-        if self.positions.len() != self.values.len() || self.positions.len() < 2 {
-            return reconstructed; // or consider returning Result
+        if self.validate().is_err() {
+            return reconstructed; // try_reconstruct reports the reason
         }
 
         for i in 0..self.positions.len() - 1 {
@@ -25,13 +300,14 @@ impl CurvatureSignal {
             let y0 = self.values[i];
             let y1 = self.values[i + 1];
 
-            let steps = 10; // adjustable resolution
+            let steps = steps_for(x1 - x0);
             for j in 0..steps {
                 let t = j as f64 / steps as f64;
-                let _x = x0 + t * (x1 - x0);
+                let x = x0 + t * (x1 - x0);
                 let y = y0 + t * (y1 - y0);
-                reconstructed.push(y); // or push (x, y) if needed
+                reconstructed.push((x, y));
             }
+            on_segment(i + 1);
         }
 
         reconstructed
@@ -39,9 +315,551 @@ impl CurvatureSignal {
 }
 
 impl CurvatureSignal {
-    /// Placeholder for Lomb-Scargle-like frequency estimation
+    /// Reconstructs the dense signal and fuses it through `engine`, using a fusion
+    /// context populated with the reconstructed curvature profile.
+    pub fn decompose<F: WaveletFusionStrategy>(
+        &self,
+        engine: &WaveletEngine<F>,
+        level: usize,
+    ) -> WaveletDecomposition {
+        let dense = self.reconstruct();
+        let context = FusionContext {
+            domain_entropy: compute_entropy(&dense),
+            curvature_profile: Some(dense.clone()),
+            domain_label: Some("curvature".into()),
+            ..FusionContext::default()
+        };
+        engine.fuse(&dense, &context, level)
+    }
+}
+
+/// Most frequencies a `FrequencyGrid::UpTo` grid generates, however high its bound.
+pub const MAX_GRID_POINTS: usize = 1 << 16;
+
+/// Frequencies (in cycles per unit position) evaluated by `CurvatureSignal::lomb_scargle`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FrequencyGrid {
+    /// Evenly spaced from `1 / span` up to the bound, oversampled 5x relative to the
+    /// `1 / span` resolution of the positions, and cut off after `MAX_GRID_POINTS`
+    /// frequencies. A non-finite bound gives no frequencies.
+    UpTo(f64),
+    /// Exactly these frequencies.
+    Explicit(Vec<f64>),
+}
+
+impl CurvatureSignal {
+    /// Lomb-Scargle periodogram of the mean-subtracted values over the (possibly uneven)
+    /// positions. Returns `(frequency, power)` pairs with power normalised by the variance,
+    /// so a pure sinusoid peaks near `n / 2`. Non-positive frequencies get zero power;
+    /// signals with fewer than two valid samples or zero span give an empty vec.
+    pub fn lomb_scargle(&self, grid: &FrequencyGrid) -> Vec<(f64, f64)> {
+        let n = self.positions.len();
+        if n != self.values.len() || n < 2 {
+            return vec![];
+        }
+        let span = self.positions[n - 1] - self.positions[0];
+        let frequencies = match grid {
+            FrequencyGrid::Explicit(frequencies) => frequencies.clone(),
+            FrequencyGrid::UpTo(max) => {
+                if !span.is_finite() || span <= 0.0 || !max.is_finite() {
+                    return vec![];
+                }
+                let step = 1.0 / (5.0 * span);
+                (5..)
+                    .map(|k| k as f64 * step)
+                    .take_while(|f| f <= max)
+                    .take(MAX_GRID_POINTS)
+                    .collect()
+            }
+        };
+
+        let mean = self.values.iter().sum::<f64>() / n as f64;
+        let centred: Vec<f64> = self.values.iter().map(|v| v - mean).collect();
+        let variance = centred.iter().map(|y| y * y).sum::<f64>() / n as f64;
+
+        frequencies
+            .into_iter()
+            .map(|f| {
+                if f <= 0.0 || variance <= 0.0 {
+                    return (f, 0.0);
+                }
+                let w = 2.0 * std::f64::consts::PI * f;
+                // The offset tau makes the sine and cosine terms orthogonal at this frequency
+                let (s2, c2) = self.positions.iter().fold((0.0, 0.0), |(s, c), t| {
+                    (s + (2.0 * w * t).sin(), c + (2.0 * w * t).cos())
+                });
+                let tau = s2.atan2(c2) / (2.0 * w);
+                let (mut yc, mut ys, mut cc, mut ss) = (0.0, 0.0, 0.0, 0.0);
+                for (t, y) in self.positions.iter().zip(&centred) {
+                    let (sin, cos) = (w * (t - tau)).sin_cos();
+                    yc += y * cos;
+                    ys += y * sin;
+                    cc += cos * cos;
+                    ss += sin * sin;
+                }
+                let term = |num: f64, den: f64| if den > 1e-12 { num * num / den } else { 0.0 };
+                (f, (term(yc, cc) + term(ys, ss)) / (2.0 * variance))
+            })
+            .collect()
+    }
+
+    /// Dominant frequencies of the signal from its Lomb-Scargle periodogram up to the
+    /// pseudo-Nyquist frequency `0.5 / mean spacing`: the periodogram's local maxima
+    /// holding at least half the strongest peak's power, strongest first.
     pub fn estimate_frequencies(&self) -> Vec<f64> {
-        // TODO: Implement Lomb-Scargle or spectral proxy
-        vec![]
+        let n = self.positions.len();
+        if n < 2 {
+            return vec![];
+        }
+        let spacing = (self.positions[n - 1] - self.positions[0]) / (n - 1) as f64;
+        let periodogram = self.lomb_scargle(&FrequencyGrid::UpTo(0.5 / spacing));
+
+        let mut peaks: Vec<(f64, f64)> = (0..periodogram.len())
+            .filter(|&i| {
+                let p = periodogram[i].1;
+                let left = i.checked_sub(1).map_or(0.0, |j| periodogram[j].1);
+                let right = periodogram.get(i + 1).map_or(0.0, |r| r.1);
+                p > 0.0 && p >= left && p > right
+            })
+            .map(|i| periodogram[i])
+            .collect();
+        peaks.sort_by(|a, b| b.1.total_cmp(&a.1));
+        let strongest = peaks.first().map_or(0.0, |p| p.1);
+        peaks.into_iter().take_while(|p| p.1 >= 0.5 * strongest).map(|(f, _)| f).collect()
+    }
+}
+
+/// Returns the indices where the signal changes sign (inflection points of a curvature trace).
+/// Between neighbouring samples of opposite sign the crossing is linearly interpolated and
+/// rounded to the nearest index. A run of exact zeros separating opposite signs reports the
+/// middle of the run (lower middle for even runs); zeros bounded by the same sign are not crossings.
+pub fn zero_crossings(signal: &[f64]) -> Vec<usize> {
+    let mut crossings = Vec::new();
+    let mut last: Option<(usize, f64)> = None; // last nonzero sample
+
+    for (i, &v) in signal.iter().enumerate() {
+        if v == 0.0 || v.is_nan() {
+            continue;
+        }
+        if let Some((j, prev)) = last
+            && prev.signum() != v.signum() {
+                let index = if i == j + 1 {
+                    let t = prev / (prev - v);
+                    (j as f64 + t).round() as usize
+                } else {
+                    (j + i) / 2
+                };
+                crossings.push(index);
+            }
+        last = Some((i, v));
+    }
+
+    crossings
+}
+
+/// Dynamic-time-warping distance between two traces of possibly different lengths:
+/// the smallest summed `|a[i] - b[j]|` over monotone alignments of the two.
+/// Two empty traces are at distance 0; an empty and a non-empty one at infinity.
+pub fn dtw_distance(a: &[f64], b: &[f64]) -> f64 {
+    dtw_path(a, b).0
+}
+
+/// Like `dtw_distance`, but also returns the optimal warping path as `(i, j)` index
+/// pairs from `(0, 0)` to the last sample of each trace.
+pub fn dtw_path(a: &[f64], b: &[f64]) -> (f64, Vec<(usize, usize)>) {
+    if a.is_empty() || b.is_empty() {
+        let distance = if a.len() == b.len() { 0.0 } else { f64::INFINITY };
+        return (distance, vec![]);
+    }
+
+    // cost[i][j] is the cheapest alignment of a[..=i] with b[..=j]
+    let (n, m) = (a.len(), b.len());
+    let mut cost = vec![vec![f64::INFINITY; m]; n];
+    for i in 0..n {
+        for j in 0..m {
+            let best_prev = match (i, j) {
+                (0, 0) => 0.0,
+                (0, _) => cost[0][j - 1],
+                (_, 0) => cost[i - 1][0],
+                _ => cost[i - 1][j - 1].min(cost[i - 1][j]).min(cost[i][j - 1]),
+            };
+            cost[i][j] = (a[i] - b[j]).abs() + best_prev;
+        }
+    }
+
+    let mut path = vec![(n - 1, m - 1)];
+    let (mut i, mut j) = (n - 1, m - 1);
+    while i > 0 || j > 0 {
+        (i, j) = match (i, j) {
+            (0, _) => (0, j - 1),
+            (_, 0) => (i - 1, 0),
+            _ => {
+                let diagonal = cost[i - 1][j - 1];
+                if diagonal <= cost[i - 1][j] && diagonal <= cost[i][j - 1] {
+                    (i - 1, j - 1)
+                } else if cost[i - 1][j] <= cost[i][j - 1] {
+                    (i - 1, j)
+                } else {
+                    (i, j - 1)
+                }
+            }
+        };
+        path.push((i, j));
+    }
+    path.reverse();
+
+    (cost[n - 1][m - 1], path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signal(positions: &[f64], values: &[f64]) -> CurvatureSignal {
+        CurvatureSignal {
+            positions: positions.to_vec(),
+            values: values.to_vec(),
+        }
+    }
+
+    fn is_increasing(positions: &[f64]) -> bool {
+        positions.windows(2).all(|w| w[1] > w[0])
+    }
+
+    #[test]
+    fn concat_continues_after_the_last_position() {
+        let a = signal(&[0.0, 1.0, 2.0], &[1.0, 2.0, 3.0]);
+        let b = signal(&[0.0, 1.0], &[10.0, 20.0]);
+        let joined = a.concat(&b);
+        assert_eq!(joined.positions, vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(joined.values, vec![1.0, 2.0, 3.0, 10.0, 20.0]);
+        assert!(is_increasing(&joined.positions));
+    }
+
+    #[test]
+    fn concat_uses_the_first_signal_spacing() {
+        let a = signal(&[0.0, 0.5], &[1.0, 2.0]);
+        let b = signal(&[100.0, 102.0, 104.0], &[3.0, 4.0, 5.0]);
+        let joined = a.concat(&b);
+        assert_eq!(joined.positions, vec![0.0, 0.5, 1.0, 3.0, 5.0]);
+        assert!(is_increasing(&joined.positions));
+    }
+
+    #[test]
+    fn concat_averages_only_overlapping_samples() {
+        let a = signal(&[0.0, 1.0], &[1.0, 2.0]);
+        // The repeated position overlaps the sample before it
+        let b = signal(&[5.0, 5.0, 6.0], &[4.0, 8.0, 9.0]);
+        let joined = a.concat(&b);
+        assert_eq!(joined.positions, vec![0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(joined.values, vec![1.0, 2.0, 6.0, 9.0]);
+    }
+
+    #[test]
+    fn concat_with_an_empty_side_copies_the_other() {
+        let a = signal(&[0.0, 1.0], &[1.0, 2.0]);
+        let empty = signal(&[], &[]);
+        assert_eq!(a.concat(&empty).values, a.values);
+        assert_eq!(empty.concat(&a).positions, a.positions);
+    }
+
+    #[test]
+    fn zero_crossings_of_a_sinusoid() {
+        // sin(2πi/20 + 0.3) crosses zero at i = 10k - 0.955
+        let wave: Vec<f64> =
+            (0..60).map(|i| (2.0 * std::f64::consts::PI * i as f64 / 20.0 + 0.3).sin()).collect();
+        assert_eq!(zero_crossings(&wave), vec![9, 19, 29, 39, 49]);
+    }
+
+    #[test]
+    fn zero_runs_report_their_middle_only_between_opposite_signs() {
+        assert_eq!(zero_crossings(&[1.0, 0.0, 0.0, 0.0, -1.0]), vec![2]);
+        assert_eq!(zero_crossings(&[1.0, 0.0, 0.0, -1.0]), vec![1]);
+        assert!(zero_crossings(&[1.0, 0.0, 2.0, 0.0]).is_empty());
+        assert!(zero_crossings(&[0.0, 0.0]).is_empty());
+    }
+
+    #[test]
+    fn decompose_matches_reconstructing_then_fusing() {
+        use crate::wavelet::{EntropyWeightedFusion, WaveletBasis};
+
+        let curve = signal(&[0.0, 1.0, 2.0, 3.0], &[0.0, 0.5, -0.25, 1.0]);
+        let engine = WaveletEngine::new(
+            vec![WaveletBasis::Haar, WaveletBasis::Daubechies(2)],
+            EntropyWeightedFusion,
+        );
+        let dense = curve.reconstruct();
+        let context = FusionContext::builder()
+            .with_domain_entropy(compute_entropy(&dense))
+            .with_curvature_profile(dense.clone())
+            .with_domain("curvature")
+            .build();
+
+        let manual = engine.fuse(&dense, &context, 2);
+        let direct = curve.decompose(&engine, 2);
+        assert_eq!(direct.coefficients, manual.coefficients);
+        assert_eq!(direct.level, 2);
+        assert_eq!(direct.basis_sources, manual.basis_sources);
+    }
+
+    #[test]
+    fn reconstruct_by_spacing_gives_wide_segments_more_samples() {
+        // Segment widths 1 and 4: at step 0.5 they get 2 and 8 samples
+        let curve = signal(&[0.0, 1.0, 5.0], &[0.0, 2.0, 6.0]);
+        let dense = curve.reconstruct_by_spacing(0.5);
+        assert_eq!(dense.len(), 10);
+        assert_eq!(&dense[..2], &[0.0, 1.0]);
+        assert_eq!(&dense[2..], &[2.0, 2.5, 3.0, 3.5, 4.0, 4.5, 5.0, 5.5]);
+
+        // A step wider than every segment still keeps one sample per segment
+        assert_eq!(curve.reconstruct_by_spacing(10.0), vec![0.0, 2.0]);
+        assert!(curve.reconstruct_by_spacing(0.0).is_empty());
+    }
+
+    #[test]
+    fn dtw_treats_a_time_stretched_copy_as_close() {
+        let pulse = [0.0, 1.0, 3.0, 1.0, 0.0, -2.0, 0.0];
+        // Every sample repeated: the same shape played at half speed
+        let stretched: Vec<f64> = pulse.iter().flat_map(|&v| [v, v]).collect();
+        let shifted: Vec<f64> = pulse.iter().map(|v| v + 1.0).collect();
+
+        assert_eq!(dtw_distance(&pulse, &stretched), 0.0);
+        assert!(dtw_distance(&pulse, &shifted) >= 7.0);
+
+        let (distance, path) = dtw_path(&pulse, &stretched);
+        assert_eq!(distance, 0.0);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(6, 13)));
+        assert!(path.windows(2).all(|w| w[1].0 >= w[0].0 && w[1].1 >= w[0].1));
+        assert_eq!(dtw_distance(&[], &pulse), f64::INFINITY);
+    }
+
+    #[test]
+    fn table_rows_increase_and_span_the_positions() {
+        let curve = signal(&[0.0, 0.5, 2.0, 3.0], &[1.0, -1.0, 0.5, 2.0]);
+        let table = curve.to_table(4);
+
+        assert_eq!(table.len(), 3 * 4 + 1);
+        assert!(is_increasing(&table.iter().map(|r| r.0).collect::<Vec<_>>()));
+        assert_eq!(table.first(), Some(&(0.0, 1.0)));
+        assert_eq!(table.last(), Some(&(3.0, 2.0)));
+        assert_eq!(table[5], (0.875, -0.625));
+        assert!(signal(&[0.0], &[1.0]).to_table(4).is_empty());
+    }
+
+
+    #[test]
+    fn builder_accepts_increasing_positions_and_rejects_regressions() {
+        let mut builder = CurvatureSignal::builder();
+        assert!(builder.is_empty());
+        for (p, v) in [(0.0, 1.0), (0.5, 2.0), (1.5, 3.0)] {
+            builder.push(p, v).unwrap();
+        }
+
+        for bad in [1.0, 1.5, f64::NAN] {
+            let err = builder.push(bad, 9.0).unwrap_err();
+            let rejected = SignalError::NonMonotonic { previous: 1.5, position: bad };
+            // Compared as text because a NaN position never equals itself
+            assert_eq!(err.to_string(), CurvatureError::from(rejected).to_string());
+        }
+        assert_eq!(builder.len(), 3);
+
+        let built = builder.build().unwrap();
+        assert_eq!(built.positions, [0.0, 0.5, 1.5]);
+        assert_eq!(built.values, [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn builder_needs_two_samples() {
+        let mut builder = CurvatureSignalBuilder::new();
+        builder.push(0.0, 1.0).unwrap();
+        let err = builder.build().unwrap_err();
+        assert_eq!(err, SignalError::TooFewSamples.into());
+    }
+
+
+    #[test]
+    fn progress_rises_to_one_while_reconstructing() {
+        let positions: Vec<f64> = (0..1001).map(|i| i as f64).collect();
+        let values: Vec<f64> = positions.iter().map(|p| (p * 0.01).sin()).collect();
+        let long = signal(&positions, &values);
+
+        let mut fractions = Vec::new();
+        let values = long.reconstruct_with_progress(4, &mut |f| fractions.push(f));
+        assert_eq!(values.len(), 1000 * 4);
+        assert!(fractions.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(fractions.last(), Some(&1.0));
+        // Throttled to roughly one report per percent rather than one per segment
+        assert!(fractions.len() > 50 && fractions.len() <= 101, "{}", fractions.len());
+    }
+
+    #[test]
+    fn progress_completes_even_without_segments() {
+        let mut fractions = Vec::new();
+        let single = signal(&[0.0], &[1.0]);
+        let values = single.reconstruct_with_progress(4, &mut |f| fractions.push(f));
+        assert!(values.is_empty());
+        assert_eq!(fractions, [1.0]);
+    }
+
+
+    /// `len` increasing positions over `[0, span)` with jittered, uneven gaps.
+    fn irregular_positions(len: usize, span: f64) -> Vec<f64> {
+        let mut state: u64 = 0x853c_49e6_748f_ea9b;
+        let mut gaps: Vec<f64> = (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                0.2 + (state >> 11) as f64 / (1u64 << 53) as f64
+            })
+            .collect();
+        let total: f64 = gaps.iter().sum();
+        gaps.iter_mut().for_each(|g| *g *= span / total);
+        gaps.iter()
+            .scan(0.0, |p, g| {
+                let at = *p;
+                *p += g;
+                Some(at)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn lomb_scargle_recovers_a_sinusoid_from_uneven_samples() {
+        let positions = irregular_positions(150, 100.0);
+        let values: Vec<f64> = positions
+            .iter()
+            .map(|t| 2.0 + (2.0 * std::f64::consts::PI * 0.23 * t + 0.4).sin())
+            .collect();
+        let uneven = signal(&positions, &values);
+
+        let dominant = uneven.estimate_frequencies();
+        assert!((dominant[0] - 0.23).abs() < 0.005, "{dominant:?}");
+
+        let periodogram = uneven.lomb_scargle(&FrequencyGrid::Explicit(vec![-0.1, 0.0, 0.23, 0.4]));
+        let powers: Vec<f64> = periodogram.iter().map(|(_, p)| *p).collect();
+        assert_eq!(&powers[..2], [0.0, 0.0]);
+        assert!((powers[2] - 75.0).abs() < 5.0, "{powers:?}");
+        assert!(powers[3] < 0.1 * powers[2]);
+    }
+
+    #[test]
+    fn lomb_scargle_bounds_its_grid_and_ignores_flat_signals() {
+        let positions = irregular_positions(40, 20.0);
+        let wave: Vec<f64> = positions.iter().map(|t| t.sin()).collect();
+        let grid = signal(&positions, &wave).lomb_scargle(&FrequencyGrid::UpTo(0.6));
+        assert!(grid.iter().all(|(f, _)| *f > 0.0 && *f <= 0.6));
+        let span = positions[39] - positions[0];
+        assert!((grid[0].0 - 1.0 / span).abs() < 1e-12);
+
+        let flat = signal(&positions, &[3.0; 40]);
+        assert!(flat.lomb_scargle(&FrequencyGrid::UpTo(0.6)).iter().all(|(_, p)| *p == 0.0));
+        assert!(flat.estimate_frequencies().is_empty());
+        assert!(signal(&[1.0], &[1.0]).lomb_scargle(&FrequencyGrid::UpTo(1.0)).is_empty());
+    }
+
+    #[test]
+    fn lomb_scargle_grid_is_finite_for_any_bound() {
+        let wave = signal(&[0.0, 0.5, 1.0, 1.5], &[0.0, 1.0, 0.0, -1.0]);
+        for max in [f64::INFINITY, f64::NAN] {
+            assert!(wave.lomb_scargle(&FrequencyGrid::UpTo(max)).is_empty());
+        }
+        let huge = wave.lomb_scargle(&FrequencyGrid::UpTo(1e12));
+        assert_eq!(huge.len(), MAX_GRID_POINTS);
+    }
+
+
+    #[test]
+    fn spline_is_c2_continuous_at_uneven_knots() {
+        let knots = signal(&[0.0, 0.7, 1.5, 3.0, 3.4, 5.0], &[0.0, 1.2, -0.4, 0.8, 0.6, -1.0]);
+        let (x, y) = (&knots.positions, &knots.values);
+        let m = knots.spline_second_derivatives().unwrap();
+        assert_eq!((m[0], m[5]), (0.0, 0.0));
+
+        // Both segments meeting at knot i have second derivative m[i] there, so C2 continuity
+        // comes down to the slopes agreeing. Slope of the cubic on segment i at fraction b:
+        let slope = |i: usize, b: f64| {
+            let (h, a) = (x[i + 1] - x[i], 1.0 - b);
+            (y[i + 1] - y[i]) / h - (3.0 * a * a - 1.0) / 6.0 * h * m[i]
+                + (3.0 * b * b - 1.0) / 6.0 * h * m[i + 1]
+        };
+        for i in 1..5 {
+            assert!((slope(i - 1, 1.0) - slope(i, 0.0)).abs() < 1e-12, "kink at knot {i}");
+        }
+
+        // The dense output passes through every knot except the last, like `reconstruct`
+        let dense = knots.reconstruct_spline();
+        let steps = knots.proportional_steps(10);
+        let mut start = 0;
+        for i in 0..5 {
+            assert!((dense[start] - y[i]).abs() < 1e-12);
+            start += steps(x[i + 1] - x[i]);
+        }
+        assert_eq!(dense.len(), start);
+    }
+
+    #[test]
+    fn spline_falls_back_to_linear_for_short_or_unordered_input() {
+        let pair = signal(&[0.0, 1.0], &[0.0, 2.0]);
+        assert_eq!(pair.reconstruct_spline(), pair.reconstruct());
+        let unordered = signal(&[0.0, 2.0, 1.0], &[0.0, 1.0, 2.0]);
+        assert_eq!(unordered.reconstruct_spline(), unordered.reconstruct());
+
+        // Collinear samples have no curvature, so the spline is the straight line
+        let line = signal(&[0.0, 1.0, 3.0], &[1.0, 2.0, 4.0]);
+        let (spline, linear) = (line.reconstruct_spline(), line.reconstruct());
+        assert_eq!(spline.len(), linear.len());
+        assert!(spline.iter().zip(&linear).all(|(s, l)| (s - l).abs() < 1e-12));
+    }
+
+
+    #[test]
+    fn try_reconstruct_names_each_problem() {
+        let mismatched = signal(&[0.0, 1.0, 2.0], &[1.0, 2.0]);
+        let err = mismatched.try_reconstruct().unwrap_err();
+        assert_eq!(err, SignalError::LengthMismatch { positions: 3, values: 2 }.into());
+        assert!(mismatched.reconstruct().is_empty());
+
+        for short in [signal(&[0.5], &[1.0]), signal(&[], &[])] {
+            assert_eq!(short.try_reconstruct().unwrap_err(), SignalError::TooFewSamples.into());
+            assert!(short.reconstruct().is_empty());
+        }
+
+        let valid = signal(&[0.0, 1.0, 2.0], &[1.0, 3.0, 2.0]);
+        assert_eq!(valid.try_reconstruct().unwrap(), valid.reconstruct());
+        assert_eq!(valid.reconstruct().len(), 20);
+    }
+
+
+    #[test]
+    fn resolution_scales_the_output_length() {
+        let even = signal(&[0.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 0.0, 1.0]);
+        for steps in [1, 5, 10, 40] {
+            assert_eq!(even.reconstruct_with_resolution(steps).len(), 3 * steps);
+        }
+        assert_eq!(even.reconstruct(), even.reconstruct_with_resolution(10));
+        assert!(even.reconstruct_with_resolution(0).is_empty());
+
+        // Mean width 1.0: the wide segment gets twice the samples of the narrow ones
+        let uneven = signal(&[0.0, 0.5, 1.0, 3.0], &[0.0, 1.0, 0.0, 1.0]);
+        assert_eq!(uneven.reconstruct_with_resolution(10).len(), 5 + 5 + 20);
+        assert_eq!(uneven.reconstruct_with_resolution(1).len(), 1 + 1 + 2);
+    }
+
+
+    #[test]
+    fn reconstructed_pairs_keep_positions_between_the_samples() {
+        let uneven = signal(&[1.0, 1.5, 4.0, 4.25], &[0.0, 2.0, -1.0, 1.0]);
+        let pairs = uneven.reconstruct_pairs();
+        let ys: Vec<f64> = pairs.iter().map(|&(_, y)| y).collect();
+        assert_eq!(ys, uneven.reconstruct());
+
+        assert!(pairs.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(pairs[0], (1.0, 0.0));
+        assert!(pairs.iter().all(|&(x, _)| (1.0..4.25).contains(&x)));
+        // Every original position except the last appears, with its sample value
+        for (p, v) in uneven.positions[..3].iter().zip(&uneven.values) {
+            assert!(pairs.contains(&(*p, *v)), "missing ({p}, {v})");
+        }
+        assert!(signal(&[0.0], &[1.0]).reconstruct_pairs().is_empty());
     }
 }
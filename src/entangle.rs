@@ -1,8 +1,10 @@
 /// Entangle map implementation for semantic domains.
 use crate::resonance::EntangleMap;
-use std::collections::HashMap;
+use crate::sem_eng::EntanglementOverlay;
+use std::collections::BTreeMap;
+use std::ops::{Add, Mul};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum SemanticDomain {
     Biological,
     Quantum,
@@ -10,14 +12,119 @@ pub enum SemanticDomain {
     Cognitive,
 }
 
+impl SemanticDomain {
+    /// Parses a field's `domain_label` (case-insensitive), e.g. "biological".
+    pub fn from_label(label: &str) -> Option<SemanticDomain> {
+        match label.to_ascii_lowercase().as_str() {
+            "biological" => Some(SemanticDomain::Biological),
+            "quantum" => Some(SemanticDomain::Quantum),
+            "linguistic" => Some(SemanticDomain::Linguistic),
+            "cognitive" => Some(SemanticDomain::Cognitive),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Coupling {
-    _strength: f64,
-    _phase_shift: f64,
+    strength: f64,
+    phase_shift: f64,
+}
+
+impl Coupling {
+    pub fn new(strength: f64, phase_shift: f64) -> Self {
+        Coupling {
+            strength,
+            phase_shift,
+        }
+    }
+
+    pub fn strength(&self) -> f64 {
+        self.strength
+    }
+
+    pub fn phase_shift(&self) -> f64 {
+        self.phase_shift
+    }
+
+    /// Returns the coupling as a complex number with magnitude `strength`
+    /// and argument `phase_shift`.
+    pub fn to_complex(&self) -> ComplexCoupling {
+        ComplexCoupling::from_polar(self.strength, self.phase_shift)
+    }
+}
+
+/// Phase-aware coupling. Multiplying composes couplings along a path
+/// (strengths multiply, phases add); adding superposes parallel routes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComplexCoupling {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl ComplexCoupling {
+    pub fn from_polar(magnitude: f64, phase: f64) -> Self {
+        ComplexCoupling {
+            re: magnitude * phase.cos(),
+            im: magnitude * phase.sin(),
+        }
+    }
+
+    pub fn magnitude(&self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    pub fn phase(&self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    pub fn to_coupling(&self) -> Coupling {
+        Coupling::new(self.magnitude(), self.phase())
+    }
 }
 
+impl Mul for ComplexCoupling {
+    type Output = ComplexCoupling;
+
+    fn mul(self, rhs: ComplexCoupling) -> ComplexCoupling {
+        ComplexCoupling {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+impl Add for ComplexCoupling {
+    type Output = ComplexCoupling;
+
+    fn add(self, rhs: ComplexCoupling) -> ComplexCoupling {
+        ComplexCoupling {
+            re: self.re + rhs.re,
+            im: self.im + rhs.im,
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct SimpleEntangleMap {
-    map: HashMap<(SemanticDomain, SemanticDomain), Coupling>,
+    map: BTreeMap<(SemanticDomain, SemanticDomain), Coupling>,
+}
+
+impl SimpleEntangleMap {
+    /// Produces one overlay per coupled domain pair, skipping near-zero strengths.
+    /// Overlays are ordered by domain pair, following `SemanticDomain`'s declaration order.
+    pub fn to_overlays(&self) -> Vec<EntanglementOverlay> {
+        self.map
+            .iter()
+            .filter(|(_, c)| c.strength.abs() > 1e-9)
+            .map(|((a, b), c)| EntanglementOverlay {
+                domain_a: a.clone(),
+                domain_b: b.clone(),
+                strength: c.strength,
+                phase_shift: c.phase_shift,
+            })
+            .collect()
+    }
 }
 
 impl EntangleMap for SimpleEntangleMap {
@@ -26,7 +133,7 @@ impl EntangleMap for SimpleEntangleMap {
 
     fn new() -> Self {
         SimpleEntangleMap {
-            map: HashMap::new(),
+            map: BTreeMap::new(),
         }
     }
 
@@ -34,13 +141,51 @@ impl EntangleMap for SimpleEntangleMap {
         self.map
             .get(&(a.clone(), b.clone()))
             .cloned()
-            .unwrap_or(Coupling {
-                _strength: 0.0,
-                _phase_shift: 0.0,
-            })
+            .unwrap_or(Coupling::new(0.0, 0.0))
     }
 
     fn update_coupling(&mut self, a: &SemanticDomain, b: &SemanticDomain, delta: Coupling) {
         self.map.insert((a.clone(), b.clone()), delta);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlays_cover_significant_couplings_in_domain_order() {
+        use SemanticDomain::*;
+        let mut map = SimpleEntangleMap::new();
+        map.update_coupling(&Cognitive, &Quantum, Coupling::new(0.4, 0.1));
+        map.update_coupling(&Quantum, &Biological, Coupling::new(0.0, 1.0));
+        map.update_coupling(&Biological, &Linguistic, Coupling::new(-0.7, 0.0));
+        map.update_coupling(&Biological, &Quantum, Coupling::new(0.9, 0.2));
+
+        let pairs: Vec<_> = map
+            .to_overlays()
+            .into_iter()
+            .map(|o| (o.domain_a, o.domain_b, o.strength))
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                (Biological, Quantum, 0.9),
+                (Biological, Linguistic, -0.7),
+                (Cognitive, Quantum, 0.4),
+            ]
+        );
+    }
+
+    #[test]
+    fn composing_couplings_along_a_path_multiplies_strengths_and_adds_phases() {
+        let hop = Coupling::new(0.5, 0.3).to_complex() * Coupling::new(0.8, 0.4).to_complex();
+        assert!((hop.magnitude() - 0.4).abs() < 1e-12);
+        assert!((hop.phase() - 0.7).abs() < 1e-12);
+
+        // Two equal routes half a turn apart interfere destructively
+        let cancelled = Coupling::new(1.0, 0.0).to_complex()
+            + Coupling::new(1.0, std::f64::consts::PI).to_complex();
+        assert!(cancelled.to_coupling().strength() < 1e-12);
+    }
+}
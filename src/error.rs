@@ -0,0 +1,140 @@
+/// Error module: the crate-wide error type.
+/// Wraps the per-module errors so fallible calls can be chained with `?`.
+/// Public fallible functions return it; the `WaveletTransform` trait keeps its associated
+/// `TransformError`. Without `std` only the variants of the built modules exist.
+#[cfg(feature = "std")]
+use crate::gkernel::GraphError;
+#[cfg(feature = "std")]
+use crate::resonance::FieldError;
+#[cfg(feature = "std")]
+use crate::wavelet::TransformError;
+use core::fmt;
+
+/// Errors raised by malformed curvature signals.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SignalError {
+    LengthMismatch { positions: usize, values: usize },
+    TooFewSamples,
+    /// A position did not strictly increase past the previous one (or was NaN).
+    NonMonotonic { previous: f64, position: f64 },
+}
+
+impl fmt::Display for SignalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignalError::LengthMismatch { positions, values } => {
+                write!(f, "{positions} positions but {values} values")
+            }
+            SignalError::TooFewSamples => write!(f, "at least two samples are required"),
+            SignalError::NonMonotonic { previous, position } => {
+                write!(f, "position {position} does not follow {previous}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for SignalError {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CurvatureError {
+    #[cfg(feature = "std")]
+    Transform(TransformError),
+    Curvature(SignalError),
+    #[cfg(feature = "std")]
+    Field(FieldError),
+    #[cfg(feature = "std")]
+    Graph(GraphError),
+}
+
+impl fmt::Display for CurvatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            CurvatureError::Transform(e) => write!(f, "transform error: {e}"),
+            CurvatureError::Curvature(e) => write!(f, "curvature signal error: {e}"),
+            #[cfg(feature = "std")]
+            CurvatureError::Field(e) => write!(f, "field error: {e}"),
+            #[cfg(feature = "std")]
+            CurvatureError::Graph(e) => write!(f, "graph error: {e}"),
+        }
+    }
+}
+
+impl core::error::Error for CurvatureError {}
+
+#[cfg(feature = "std")]
+impl From<TransformError> for CurvatureError {
+    fn from(e: TransformError) -> Self {
+        CurvatureError::Transform(e)
+    }
+}
+
+impl From<SignalError> for CurvatureError {
+    fn from(e: SignalError) -> Self {
+        CurvatureError::Curvature(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<FieldError> for CurvatureError {
+    fn from(e: FieldError) -> Self {
+        CurvatureError::Field(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<GraphError> for CurvatureError {
+    fn from(e: GraphError) -> Self {
+        CurvatureError::Graph(e)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::curvature_signal::CurvatureSignal;
+    use crate::resonance::{GridField, Position};
+    use crate::wavelet::{EntropyWeightedFusion, daubechies_decompose};
+
+    fn observe_then_decompose(field: &GridField, x: f64) -> Result<usize, CurvatureError> {
+        field.try_observe(&Position { x, y: 0.0 })?;
+        Ok(daubechies_decompose(&[1.0, 2.0, 3.0], 4)?.approximation.len())
+    }
+
+    #[test]
+    fn fallible_calls_from_different_modules_chain_with_question_mark() {
+        let field = GridField::new(vec![vec![0.5; 4]; 4]);
+        assert_eq!(
+            observe_then_decompose(&field, 9.0),
+            Err(CurvatureError::Field(FieldError::OutOfBounds { x: 9.0, y: 0.0 }))
+        );
+        assert_eq!(
+            observe_then_decompose(&field, 1.0),
+            Err(CurvatureError::Transform(TransformError::InvalidLength))
+        );
+    }
+
+    #[test]
+    fn module_errors_are_wrapped() {
+        assert!(matches!(
+            EntropyWeightedFusion::try_fuse(&[]),
+            Err(CurvatureError::Transform(TransformError::EmptyInput))
+        ));
+        let mut builder = CurvatureSignal::builder();
+        builder.push(1.0, 0.0).unwrap();
+        assert!(matches!(
+            builder.push(0.5, 0.0),
+            Err(CurvatureError::Curvature(SignalError::NonMonotonic { .. }))
+        ));
+        assert_eq!(
+            builder.build().unwrap_err(),
+            CurvatureError::Curvature(SignalError::TooFewSamples)
+        );
+    }
+
+    #[test]
+    fn display_names_the_source_module() {
+        let e = CurvatureError::from(TransformError::EmptyInput);
+        assert_eq!(e.to_string(), "transform error: no decompositions to fuse");
+    }
+}
@@ -0,0 +1,63 @@
+//! Generators module: reproducible test signals for demos and the DSP routines.
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::f64::consts::PI;
+
+/// Unit-amplitude sine of `freq` cycles per unit time, sampled `n` times at `sample_rate`.
+pub fn sine(freq: f64, n: usize, sample_rate: f64) -> Vec<f64> {
+    (0..n)
+        .map(|i| (2.0 * PI * freq * i as f64 / sample_rate).sin())
+        .collect()
+}
+
+/// Linear chirp sweeping from `f0` to `f1` cycles per sample over `n` samples.
+pub fn chirp(f0: f64, f1: f64, n: usize) -> Vec<f64> {
+    let rate = (f1 - f0) / n.max(1) as f64;
+    (0..n)
+        .map(|i| {
+            let t = i as f64;
+            (2.0 * PI * (f0 * t + 0.5 * rate * t * t)).sin()
+        })
+        .collect()
+}
+
+/// Unit step: 0 before index `at`, 1 from `at` onwards.
+pub fn step(n: usize, at: usize) -> Vec<f64> {
+    (0..n).map(|i| if i >= at { 1.0 } else { 0.0 }).collect()
+}
+
+/// Uniform white noise in `[-1, 1)`; the same `seed` always gives the same samples.
+pub fn white_noise(n: usize, seed: u64) -> Vec<f64> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..n).map(|_| rng.random_range(-1.0..1.0)).collect()
+}
+
+/// Unit impulse at index `at` (all zeros if `at >= n`).
+pub fn impulse(n: usize, at: usize) -> Vec<f64> {
+    (0..n).map(|i| if i == at { 1.0 } else { 0.0 }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spectrum::{bin_frequency, magnitude_spectrum};
+
+    #[test]
+    fn sine_peaks_at_its_frequency() {
+        let (n, sample_rate) = (500, 100.0);
+        let spectrum = magnitude_spectrum(&sine(7.0, n, sample_rate));
+        let peak = (0..spectrum.len()).max_by(|&a, &b| spectrum[a].total_cmp(&spectrum[b]));
+        assert_eq!(bin_frequency(peak.unwrap(), n, sample_rate), 7.0);
+    }
+
+    #[test]
+    fn generators_are_reproducible_and_shaped() {
+        assert_eq!(white_noise(16, 3), white_noise(16, 3));
+        assert_ne!(white_noise(16, 3), white_noise(16, 4));
+        assert!(white_noise(256, 1).iter().all(|v| (-1.0..1.0).contains(v)));
+        assert_eq!(step(4, 2), [0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(impulse(4, 1), [0.0, 1.0, 0.0, 0.0]);
+        assert_eq!(impulse(2, 5), [0.0, 0.0]);
+        assert_eq!(chirp(0.25, 0.25, 4).len(), 4);
+    }
+}
@@ -1,6 +1,26 @@
 /// Graph kernel implementation for resonance nodes and edges.
 /// Defines structures and methods for managing resonance graphs.
 /// This module is part of the curvature crate.
+use crate::error::CurvatureError;
+use std::collections::{BTreeMap, HashMap};
+
+/// Errors raised when editing a graph.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GraphError {
+    /// Shifting node `id` by `offset` overflowed `usize`.
+    IdOverflow { id: usize, offset: usize },
+}
+
+impl std::fmt::Display for GraphError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphError::IdOverflow { id, offset } => write!(f, "node id {id} + {offset} overflows"),
+        }
+    }
+}
+
+impl std::error::Error for GraphError {}
+
 #[allow(dead_code)]
 pub struct ResonanceNode {
     id: usize,
@@ -16,6 +36,16 @@ pub struct ResonanceEdge {
     frequency: f64,
 }
 
+/// How nodes are weighted by `GraphKernel::weighted_mean_coherence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoherenceWeighting {
+    Uniform,
+    /// Number of incident edges.
+    Degree,
+    /// Summed `|amplitude|` of incident edges.
+    EdgeAmplitude,
+}
+
 pub struct GraphKernel {
     nodes: Vec<ResonanceNode>,
     edges: Vec<ResonanceEdge>,
@@ -40,6 +70,13 @@ impl ResonanceEdge {
 }
 
 impl GraphKernel {
+    pub fn new() -> Self {
+        GraphKernel {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
     pub fn add_node(&mut self, node: ResonanceNode) {
         self.nodes.push(node);
     }
@@ -48,6 +85,13 @@ impl GraphKernel {
         self.edges.push(edge);
     }
 
+    /// Removes the node with `id`, returning it. Edges touching it are left dangling
+    /// until `compact` drops them.
+    pub fn remove_node(&mut self, id: usize) -> Option<ResonanceNode> {
+        let index = self.nodes.iter().position(|node| node.id == id)?;
+        Some(self.nodes.remove(index))
+    }
+
     pub fn get_node(&self, id: usize) -> Option<&ResonanceNode> {
         self.nodes.iter().find(|node| node.id == id)
     }
@@ -56,4 +100,265 @@ impl GraphKernel {
             .iter()
             .find(|edge| edge.from == from && edge.to == to)
     }
+
+    /// Groups nodes into components connected by edges (treated as undirected).
+    /// Components are ordered by their smallest node id and nodes within each are
+    /// ascending, so the output is deterministic. Edges to unknown nodes are ignored.
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut ids: Vec<usize> = self.nodes.iter().map(|n| n.id).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        let index: BTreeMap<usize, usize> = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+
+        let mut parent: Vec<usize> = (0..ids.len()).collect();
+        fn find(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+
+        for edge in &self.edges {
+            if let (Some(&a), Some(&b)) = (index.get(&edge.from), index.get(&edge.to)) {
+                let (ra, rb) = (find(&mut parent, a), find(&mut parent, b));
+                // Keep the smaller index as root so roots follow id order
+                if ra != rb {
+                    parent[ra.max(rb)] = ra.min(rb);
+                }
+            }
+        }
+
+        let mut groups: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for (i, &id) in ids.iter().enumerate() {
+            let root = find(&mut parent, i);
+            groups.entry(root).or_default().push(id);
+        }
+
+        groups.into_values().collect()
+    }
+
+    /// Mean node coherence under `weighting`, so well-connected nodes can count for more.
+    /// Falls back to the uniform mean when every weight is zero (e.g. no edges);
+    /// returns 0.0 for an empty graph.
+    pub fn weighted_mean_coherence(&self, weighting: CoherenceWeighting) -> f64 {
+        let weight = |id: usize| -> f64 {
+            // Each endpoint touching `id` counts once, so self-loops count twice
+            let incident = self.edges.iter().flat_map(|e| {
+                [e.from, e.to].into_iter().filter(move |&end| end == id).map(move |_| e.amplitude)
+            });
+            match weighting {
+                CoherenceWeighting::Uniform => 1.0,
+                CoherenceWeighting::Degree => incident.count() as f64,
+                CoherenceWeighting::EdgeAmplitude => incident.map(f64::abs).sum(),
+            }
+        };
+
+        let weights: Vec<f64> = self.nodes.iter().map(|n| weight(n.id)).collect();
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 {
+            return match weighting {
+                CoherenceWeighting::Uniform => 0.0,
+                _ => self.weighted_mean_coherence(CoherenceWeighting::Uniform),
+            };
+        }
+
+        self.nodes.iter().zip(&weights).map(|(n, w)| n.coherence * w).sum::<f64>() / total
+    }
+
+    /// Renumbers node ids to `0..n` in ascending order of their old ids, remaps edge
+    /// endpoints, and drops edges referencing missing nodes. Returns the old-to-new id mapping.
+    pub fn compact(&mut self) -> HashMap<usize, usize> {
+        let mut ids: Vec<usize> = self.nodes.iter().map(|n| n.id).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        let mapping: HashMap<usize, usize> =
+            ids.into_iter().enumerate().map(|(new, old)| (old, new)).collect();
+
+        for node in &mut self.nodes {
+            node.id = mapping[&node.id];
+        }
+        self.edges.retain_mut(|edge| match (mapping.get(&edge.from), mapping.get(&edge.to)) {
+            (Some(&from), Some(&to)) => {
+                edge.from = from;
+                edge.to = to;
+                true
+            }
+            _ => false,
+        });
+
+        mapping
+    }
+
+    /// Absorbs `other`, shifting its node ids and edge endpoints by `id_offset`
+    /// so they do not collide with this graph's ids. If any shifted id overflows,
+    /// returns `GraphError::IdOverflow` and leaves this graph unchanged.
+    pub fn merge(&mut self, other: GraphKernel, id_offset: usize) -> Result<(), CurvatureError> {
+        let shift = |id: usize| {
+            id.checked_add(id_offset)
+                .ok_or(GraphError::IdOverflow { id, offset: id_offset })
+        };
+        let nodes = other
+            .nodes
+            .into_iter()
+            .map(|mut node| {
+                node.id = shift(node.id)?;
+                Ok(node)
+            })
+            .collect::<Result<Vec<_>, GraphError>>()?;
+        let edges = other
+            .edges
+            .into_iter()
+            .map(|mut edge| {
+                edge.from = shift(edge.from)?;
+                edge.to = shift(edge.to)?;
+                Ok(edge)
+            })
+            .collect::<Result<Vec<_>, GraphError>>()?;
+        self.nodes.extend(nodes);
+        self.edges.extend(edges);
+        Ok(())
+    }
+}
+
+impl Default for GraphKernel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Finds node pairs whose phase difference stays constant (within `tolerance` radians of
+/// its initial value) across every time step. `phase_history[t][i]` is node `i`'s phase
+/// at step `t`; differences are wrapped to (-pi, pi]. Pairs are returned as `(i, j)` with `i < j`.
+pub fn detect_phase_locking(phase_history: &[Vec<f64>], tolerance: f64) -> Vec<(usize, usize)> {
+    let wrap = |a: f64| {
+        let two_pi = 2.0 * std::f64::consts::PI;
+        let w = a.rem_euclid(two_pi);
+        if w > std::f64::consts::PI { w - two_pi } else { w }
+    };
+
+    let nodes = phase_history.iter().map(|p| p.len()).min().unwrap_or(0);
+    let mut locked = Vec::new();
+
+    for i in 0..nodes {
+        for j in (i + 1)..nodes {
+            let initial = phase_history[0][j] - phase_history[0][i];
+            if phase_history
+                .iter()
+                .all(|p| wrap(p[j] - p[i] - initial).abs() <= tolerance)
+            {
+                locked.push((i, j));
+            }
+        }
+    }
+
+    locked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(ids: &[usize], edges: &[(usize, usize)]) -> GraphKernel {
+        let mut graph = GraphKernel::new();
+        for &id in ids {
+            graph.add_node(ResonanceNode::new(id, 0.5, 0.0));
+        }
+        for &(from, to) in edges {
+            graph.add_edge(ResonanceEdge::new(from, to, 1.0, 1.0));
+        }
+        graph
+    }
+
+    #[test]
+    fn merge_offsets_the_absorbed_graph() {
+        let mut a = graph(&[0, 1], &[(0, 1)]);
+        a.merge(graph(&[0, 1, 2], &[(0, 2), (2, 1)]), 10).unwrap();
+
+        assert!(a.get_edge(0, 1).is_some());
+        assert!(a.get_edge(10, 12).is_some());
+        assert!(a.get_edge(12, 11).is_some());
+        assert!(a.get_edge(0, 2).is_none());
+        assert_eq!(a.connected_components(), vec![vec![0, 1], vec![10, 11, 12]]);
+    }
+
+    #[test]
+    fn merge_rejects_overflowing_ids_and_keeps_the_graph() {
+        let mut a = graph(&[0, 1], &[(0, 1)]);
+        let offset = usize::MAX - 1;
+        assert_eq!(
+            a.merge(graph(&[0, 1, 2], &[(0, 2)]), offset),
+            Err(CurvatureError::Graph(GraphError::IdOverflow { id: 2, offset }))
+        );
+        assert_eq!(a.connected_components(), vec![vec![0, 1]]);
+    }
+
+    #[test]
+    fn components_are_sorted_by_smallest_id_then_ascending() {
+        // Insertion order and edge direction are deliberately scrambled
+        let ids = [9, 4, 7, 1, 12, 3, 8];
+        let edges = [(12, 4), (8, 3), (9, 1), (1, 7), (5, 9)];
+        let expected = vec![vec![1, 7, 9], vec![3, 8], vec![4, 12]];
+        for _ in 0..5 {
+            assert_eq!(graph(&ids, &edges).connected_components(), expected);
+        }
+        let reversed: Vec<usize> = ids.iter().rev().copied().collect();
+        assert_eq!(graph(&reversed, &edges).connected_components(), expected);
+    }
+
+    #[test]
+    fn phase_locking_reports_only_the_synchronized_pair() {
+        // Nodes 0 and 1 advance together a fixed 0.5 rad apart; node 2 drifts
+        let history: Vec<Vec<f64>> = (0..20)
+            .map(|t| {
+                let t = t as f64;
+                vec![0.3 * t, 0.3 * t + 0.5, 0.45 * t]
+            })
+            .collect();
+        assert_eq!(detect_phase_locking(&history, 0.05), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn phase_locking_survives_wrapping_past_pi() {
+        // The raw difference jumps by 2π when one phase wraps; the wrapped one does not
+        let history = vec![vec![3.1, 3.0], vec![-3.1, 3.08], vec![-3.0, -3.1]];
+        assert_eq!(detect_phase_locking(&history, 0.1), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn degree_weighting_pulls_the_mean_toward_the_hub() {
+        let mut star = GraphKernel::new();
+        star.add_node(ResonanceNode::new(0, 0.9, 0.0));
+        for leaf in 1..=3 {
+            star.add_node(ResonanceNode::new(leaf, 0.1, 0.0));
+            star.add_edge(ResonanceEdge::new(0, leaf, 2.0, 1.0));
+        }
+
+        let uniform = star.weighted_mean_coherence(CoherenceWeighting::Uniform);
+        let degree = star.weighted_mean_coherence(CoherenceWeighting::Degree);
+        let amplitude = star.weighted_mean_coherence(CoherenceWeighting::EdgeAmplitude);
+        assert!((uniform - 0.3).abs() < 1e-12);
+        assert!((degree - 0.5).abs() < 1e-12);
+        assert!((amplitude - 0.5).abs() < 1e-12);
+
+        // Without edges every weighting falls back to the uniform mean
+        let isolated = graph(&[0, 1], &[]);
+        assert_eq!(isolated.weighted_mean_coherence(CoherenceWeighting::Degree), 0.5);
+        assert_eq!(GraphKernel::new().weighted_mean_coherence(CoherenceWeighting::Uniform), 0.0);
+    }
+
+
+    #[test]
+    fn compact_renumbers_after_removing_a_middle_node() {
+        let mut g = graph(&[0, 4, 7, 9], &[(0, 4), (4, 7), (7, 9), (9, 0), (0, 12)]);
+        assert!(g.remove_node(4).is_some());
+        let mapping = g.compact();
+
+        assert_eq!(mapping, HashMap::from([(0, 0), (7, 1), (9, 2)]));
+        let ids: Vec<usize> = g.nodes.iter().map(|n| n.id).collect();
+        assert_eq!(ids, [0, 1, 2]);
+        let edges: Vec<(usize, usize)> = g.edges.iter().map(|e| (e.from, e.to)).collect();
+        assert_eq!(edges, [(1, 2), (2, 0)]);
+        assert_eq!(g.compact(), HashMap::from([(0, 0), (1, 1), (2, 2)]));
+    }
 }
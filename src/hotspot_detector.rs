@@ -1,33 +1,468 @@
 /// Hotspot detection module for curvature signals.
 /// Defines traits and implementations for identifying hotspots
 /// in curvature data.
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+use crate::error::{CurvatureError, SignalError};
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
 pub trait HotspotDetector {                                                                             
     fn detect(&self, signal: &[f64]) -> Vec<usize>;
 }
 
 
+/// Ordering of the indices returned by `PercentileHotspot`.
+/// Value orderings are stable, so equal values stay in index order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HotspotOrder {
+    #[default]
+    Index,
+    DescendingValue,
+    AscendingValue,
+}
+
 #[derive(Debug, Clone)]
 pub struct PercentileHotspot {
     pub percentile: f64, // e.g. 80.0 for top 20%
+    pub order: HotspotOrder,
 }
 
 impl PercentileHotspot {
+    /// Creates a detector returning hotspots in index order.
+    pub fn new(percentile: f64) -> Self {
+        PercentileHotspot {
+            percentile,
+            order: HotspotOrder::Index,
+        }
+    }
+
+    /// Sets the ordering of the returned indices.
+    pub fn with_order(mut self, order: HotspotOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    fn apply_order(&self, signal: &[f64], mut indices: Vec<usize>) -> Vec<usize> {
+        let by_value = |a: &usize, b: &usize| {
+            signal[*a].partial_cmp(&signal[*b]).unwrap_or(core::cmp::Ordering::Equal)
+        };
+        match self.order {
+            HotspotOrder::Index => {}
+            HotspotOrder::AscendingValue => indices.sort_by(by_value),
+            HotspotOrder::DescendingValue => indices.sort_by(|a, b| by_value(b, a)),
+        }
+        indices
+    }
+
     pub fn detect(&self, signal: &[f64]) -> Vec<usize> {
         if signal.is_empty() {
             return vec![];
         }
 
         let mut sorted = signal.to_vec();
-        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
 
         let index = ((self.percentile / 100.0) * sorted.len() as f64).floor() as usize;
         let threshold = sorted[index.min(sorted.len() - 1)];
 
-        signal
+        let hotspots = signal
             .iter()
             .enumerate()
             .filter_map(|(i, &v)| if v >= threshold { Some(i) } else { None })
+            .collect();
+        self.apply_order(signal, hotspots)
+    }
+
+    /// Detects hotspots against a weighted percentile threshold: the smallest value at which
+    /// cumulative weight exceeds the percentile fraction of the total weight. Fails with
+    /// `SignalError::LengthMismatch` (signal length, weight count) unless there is one weight
+    /// per sample. Values sort by `f64::total_cmp`, so NaN samples order after the rest.
+    pub fn detect_weighted(
+        &self,
+        signal: &[f64],
+        weights: &[f64],
+    ) -> Result<Vec<usize>, CurvatureError> {
+        if signal.len() != weights.len() {
+            let (positions, values) = (signal.len(), weights.len());
+            return Err(SignalError::LengthMismatch { positions, values }.into());
+        }
+        if signal.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut sorted: Vec<(f64, f64)> = signal.iter().cloned().zip(weights.iter().cloned()).collect();
+        sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let total: f64 = weights.iter().sum();
+        let target = (self.percentile / 100.0) * total;
+        let mut cumulative = 0.0;
+        let threshold = sorted
+            .iter()
+            .find(|(_, w)| {
+                cumulative += w;
+                cumulative > target
+            })
+            .unwrap_or(&sorted[sorted.len() - 1])
+            .0;
+
+        let hotspots = signal
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &v)| if v >= threshold { Some(i) } else { None })
+            .collect();
+        Ok(self.apply_order(signal, hotspots))
+    }
+
+    /// Runs `detect` over each signal independently.
+    /// Parallelized across signals when the `rayon` feature is enabled.
+    pub fn detect_batch(&self, signals: &[&[f64]]) -> Vec<Vec<usize>> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            signals.par_iter().map(|signal| self.detect(signal)).collect()
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            signals.iter().map(|signal| self.detect(signal)).collect()
+        }
+    }
+}
+
+
+/// The top 20% (`percentile: 80.0`) in index order.
+impl Default for PercentileHotspot {
+    fn default() -> Self {
+        PercentileHotspot::new(80.0)
+    }
+}
+
+impl HotspotDetector for PercentileHotspot {
+    fn detect(&self, signal: &[f64]) -> Vec<usize> {
+        PercentileHotspot::detect(self, signal)
+    }
+}
+
+/// Percentile detector that tunes its own percentile: binary-searches for the
+/// percentile whose hotspot set size is closest to `target_count`. Ties in the
+/// signal can make the exact count unreachable.
+#[derive(Debug, Clone)]
+pub struct AutoPercentileHotspot {
+    pub target_count: usize,
+}
+
+impl HotspotDetector for AutoPercentileHotspot {
+    fn detect(&self, signal: &[f64]) -> Vec<usize> {
+        // Hotspot count never grows as the percentile rises
+        let miss = |count: usize| count.abs_diff(self.target_count);
+        let (mut low, mut high) = (0.0, 100.0);
+        let mut best = PercentileHotspot::new(low).detect(signal);
+        for _ in 0..64 {
+            let mid = (low + high) / 2.0;
+            let hotspots = PercentileHotspot::new(mid).detect(signal);
+            if miss(hotspots.len()) < miss(best.len()) {
+                best = hotspots.clone();
+            }
+            match hotspots.len().cmp(&self.target_count) {
+                core::cmp::Ordering::Greater => low = mid,
+                core::cmp::Ordering::Less => high = mid,
+                core::cmp::Ordering::Equal => return hotspots,
+            }
+        }
+        best
+    }
+}
+
+/// Peak detector based on 1D topological persistence: sweeping a threshold down from the
+/// maximum, each peak is born at its height and dies where its region merges into a taller
+/// peak's; persistence is the height difference between the peak and that merge saddle.
+/// Returns peaks whose persistence exceeds `min_persistence`, in index order. The global
+/// maximum never merges and is always returned; NaN samples separate regions.
+#[derive(Debug, Clone)]
+pub struct PersistenceHotspot {
+    pub min_persistence: f64,
+}
+
+impl PersistenceHotspot {
+    /// Returns every peak index with its persistence (infinite for the global maximum),
+    /// in index order.
+    pub fn persistence(&self, signal: &[f64]) -> Vec<(usize, f64)> {
+        let mut order: Vec<usize> = (0..signal.len()).filter(|&i| !signal[i].is_nan()).collect();
+        // Highest first; equal heights in index order so plateaus resolve deterministically
+        order.sort_by(|&a, &b| {
+            signal[b].partial_cmp(&signal[a]).unwrap_or(core::cmp::Ordering::Equal)
+        });
+
+        // Union-find over added samples; each root remembers its component's peak
+        let mut parent: Vec<Option<usize>> = vec![None; signal.len()];
+        let mut peak_of = vec![0; signal.len()];
+        let mut peaks = Vec::new();
+        fn find(parent: &mut [Option<usize>], mut i: usize) -> usize {
+            while let Some(p) = parent[i].filter(|&p| p != i) {
+                parent[i] = parent[p];
+                i = p;
+            }
+            i
+        }
+
+        for &i in &order {
+            parent[i] = Some(i);
+            peak_of[i] = i;
+            let neighbours = [i.checked_sub(1), (i + 1 < signal.len()).then_some(i + 1)];
+            let mut roots = Vec::with_capacity(2);
+            for j in neighbours.into_iter().flatten() {
+                if parent[j].is_some() {
+                    let root = find(&mut parent, j);
+                    if !roots.contains(&root) {
+                        roots.push(root);
+                    }
+                }
+            }
+
+            match roots.as_slice() {
+                [] => {}
+                [root] => parent[i] = Some(*root),
+                [left, right] => {
+                    // The lower peak dies at this saddle and its component joins the taller one
+                    let (tall, short) = if signal[peak_of[*left]] >= signal[peak_of[*right]] {
+                        (*left, *right)
+                    } else {
+                        (*right, *left)
+                    };
+                    peaks.push((peak_of[short], signal[peak_of[short]] - signal[i]));
+                    parent[short] = Some(tall);
+                    parent[i] = Some(tall);
+                }
+                _ => unreachable!("a sample has at most two neighbours"),
+            }
+        }
+
+        // Components that never merged (the global maximum, or regions cut off by NaNs)
+        // persist forever; their roots are their peaks
+        for &i in &order {
+            if find(&mut parent, i) == i {
+                peaks.push((peak_of[i], f64::INFINITY));
+            }
+        }
+        peaks.sort_by_key(|&(i, _)| i);
+        peaks
+    }
+}
+
+impl HotspotDetector for PersistenceHotspot {
+    fn detect(&self, signal: &[f64]) -> Vec<usize> {
+        self.persistence(signal)
+            .into_iter()
+            .filter(|&(_, p)| p > self.min_persistence)
+            .map(|(i, _)| i)
             .collect()
     }
 }
 
+/// Flags indices whose value lies more than `threshold` standard deviations above the
+/// signal mean (population statistics over the non-NaN samples), in index order.
+/// Unlike percentiles this can report nothing: a zero-variance signal never has hotspots.
+#[derive(Debug, Clone)]
+pub struct ZScoreHotspot {
+    pub threshold: f64,
+}
+
+impl HotspotDetector for ZScoreHotspot {
+    fn detect(&self, signal: &[f64]) -> Vec<usize> {
+        let non_nan = || signal.iter().filter(|v| !v.is_nan());
+        let count = non_nan().count();
+        // Checked exactly: rounding in the mean can leave a flat signal a tiny nonzero std
+        let first = non_nan().next();
+        let flat = non_nan().all(|v| Some(v) == first);
+        if count == 0 || flat {
+            return vec![];
+        }
+        let mean = non_nan().sum::<f64>() / count as f64;
+        let std = (non_nan().map(|v| (v - mean) * (v - mean)).sum::<f64>() / count as f64).sqrt();
+        if std <= 0.0 || !std.is_finite() {
+            return vec![];
+        }
+
+        (0..signal.len())
+            .filter(|&i| (signal[i] - mean) / std > self.threshold)
+            .collect()
+    }
+}
+
+/// Runs `detector` over windows of `chunk` samples that overlap by `overlap`,
+/// returning deduplicated global indices in ascending order. Bounds the memory each
+/// detection touches; detectors that depend on global statistics (such as percentiles)
+/// see only one window at a time. `overlap` is clamped below `chunk`.
+pub fn chunked_detect(
+    signal: &[f64],
+    chunk: usize,
+    overlap: usize,
+    detector: &impl HotspotDetector,
+) -> Vec<usize> {
+    if chunk == 0 || signal.len() <= chunk {
+        return detector.detect(signal);
+    }
+
+    let stride = chunk - overlap.min(chunk - 1);
+    let mut indices = alloc::collections::BTreeSet::new();
+    let mut start = 0;
+    loop {
+        let end = (start + chunk).min(signal.len());
+        indices.extend(detector.detect(&signal[start..end]).into_iter().map(|i| i + start));
+        if end == signal.len() {
+            break;
+        }
+        start += stride;
+    }
+
+    indices.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    const SIGNAL: [f64; 6] = [0.1, 0.9, 0.3, 1.5, 0.2, 1.1];
+
+    #[test]
+    fn percentile_hotspots_default_to_index_order() {
+        assert_eq!(PercentileHotspot::new(50.0).detect(&SIGNAL), vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn descending_value_puts_the_strongest_hotspot_first() {
+        let detector = PercentileHotspot::new(50.0).with_order(HotspotOrder::DescendingValue);
+        assert_eq!(detector.detect(&SIGNAL), vec![3, 5, 1]);
+        let detector = PercentileHotspot::new(50.0).with_order(HotspotOrder::AscendingValue);
+        assert_eq!(detector.detect(&SIGNAL), vec![1, 5, 3]);
+    }
+
+    #[test]
+    fn value_orders_keep_ties_in_index_order() {
+        let detector = PercentileHotspot::new(0.0).with_order(HotspotOrder::DescendingValue);
+        assert_eq!(detector.detect(&[1.0, 2.0, 1.0, 2.0]), vec![1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn detect_batch_matches_detect_per_signal() {
+        let signals: [&[f64]; 4] = [&SIGNAL, &[], &[3.0, 1.0, 2.0], &[0.5; 5]];
+        let detector = PercentileHotspot::new(60.0);
+        let expected: Vec<_> = signals.iter().map(|s| detector.detect(s)).collect();
+        assert_eq!(detector.detect_batch(&signals), expected);
+    }
+
+    #[test]
+    fn upweighting_low_values_lowers_the_weighted_threshold() {
+        let signal = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let detector = PercentileHotspot::new(50.0);
+        assert_eq!(detector.detect_weighted(&signal, &[1.0; 6]), Ok(vec![3, 4, 5]));
+        // Heavy weight on the largest values leaves less cumulative weight below them
+        let weights = [0.1, 0.1, 0.1, 0.1, 3.0, 3.0];
+        assert_eq!(detector.detect_weighted(&signal, &weights), Ok(vec![4, 5]));
+        let weights = [3.0, 3.0, 0.1, 0.1, 0.1, 0.1];
+        assert_eq!(detector.detect_weighted(&signal, &weights), Ok(vec![1, 2, 3, 4, 5]));
+        assert_eq!(detector.detect_weighted(&[], &[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn weighted_detection_rejects_mismatched_weights() {
+        let detector = PercentileHotspot::new(50.0);
+        let mismatch = SignalError::LengthMismatch { positions: 3, values: 2 };
+        assert_eq!(detector.detect_weighted(&[1.0, 2.0, 3.0], &[1.0; 2]), Err(mismatch.into()));
+    }
+
+    #[test]
+    fn weighted_detection_orders_nan_after_every_value() {
+        let detector = PercentileHotspot::new(50.0);
+        let signal = [3.0, f64::NAN, 1.0, 2.0, 4.0];
+        // NaN sorts after 4.0, so the weighted median is 3.0 and NaN is never flagged
+        assert_eq!(detector.detect_weighted(&signal, &[1.0; 5]), Ok(vec![0, 4]));
+    }
+
+    /// Flags samples above a fixed level, so detection does not depend on the window.
+    struct Above(f64);
+
+    impl HotspotDetector for Above {
+        fn detect(&self, signal: &[f64]) -> Vec<usize> {
+            (0..signal.len()).filter(|&i| signal[i] > self.0).collect()
+        }
+    }
+
+    #[test]
+    fn chunked_detection_matches_whole_signal_detection() {
+        let signal: Vec<f64> = (0..100).map(|i| ((i * 37) % 23) as f64).collect();
+        let detector = Above(17.0);
+        let whole = detector.detect(&signal);
+        assert!(!whole.is_empty());
+        for (chunk, overlap) in [(10, 3), (16, 0), (7, 6), (99, 50), (200, 10)] {
+            assert_eq!(chunked_detect(&signal, chunk, overlap, &detector), whole);
+        }
+    }
+
+    #[test]
+    fn auto_percentile_lands_within_one_of_the_target() {
+        let signal: Vec<f64> = (0..40).map(|i| ((i * 17) % 29) as f64 * 0.1).collect();
+        let hotspots = AutoPercentileHotspot { target_count: 5 }.detect(&signal);
+        assert!(hotspots.len().abs_diff(5) <= 1, "{hotspots:?}");
+        // The chosen hotspots are the largest values
+        let smallest_hot = hotspots.iter().map(|&i| signal[i]).fold(f64::INFINITY, f64::min);
+        let above = signal.iter().filter(|&&v| v >= smallest_hot).count();
+        assert_eq!(above, hotspots.len());
+    }
+
+
+    #[test]
+    fn only_persistent_peaks_survive() {
+        let signal = [0.0, 3.0, 0.5, 0.8, 0.6, 5.0, 1.0, 1.2, 0.0, 2.0, 0.0];
+        let detector = PersistenceHotspot { min_persistence: 1.0 };
+        let persistence = detector.persistence(&signal);
+        let expected = [(1, 2.5), (3, 0.2), (5, f64::INFINITY), (7, 0.2), (9, 2.0)];
+        assert_eq!(persistence.len(), expected.len());
+        for (&(i, p), &(j, q)) in persistence.iter().zip(&expected) {
+            assert_eq!(i, j);
+            assert!(p == q || (p - q).abs() < 1e-12, "peak {i}: {p} != {q}");
+        }
+        assert_eq!(detector.detect(&signal), [1, 5, 9]);
+        assert_eq!(PersistenceHotspot { min_persistence: 0.1 }.detect(&signal).len(), 5);
+    }
+
+    #[test]
+    fn nan_samples_split_persistence_regions() {
+        let detector = PersistenceHotspot { min_persistence: 10.0 };
+        assert_eq!(detector.detect(&[0.0, 1.0, f64::NAN, 2.0, 0.5]), [1, 3]);
+        assert!(detector.detect(&[]).is_empty());
+    }
+
+
+    #[test]
+    fn z_score_ignores_flat_signals() {
+        let detector = ZScoreHotspot { threshold: 0.5 };
+        assert!(detector.detect(&[0.3; 12]).is_empty());
+        assert!(detector.detect(&[0.1, 0.1, f64::NAN, 0.1]).is_empty());
+        assert!(detector.detect(&[]).is_empty());
+        assert!(detector.detect(&[f64::NAN; 3]).is_empty());
+    }
+
+    #[test]
+    fn z_score_flags_a_single_spike() {
+        let mut signal = [1.0; 10];
+        signal[6] = 11.0;
+        // Mean 2, population std 3: the spike sits 3 standard deviations up
+        assert_eq!(ZScoreHotspot { threshold: 2.0 }.detect(&signal), [6]);
+        assert!(ZScoreHotspot { threshold: 3.0 }.detect(&signal).is_empty());
+        // Samples below the mean never count, however far away
+        signal[6] = -9.0;
+        assert!(ZScoreHotspot { threshold: 1.0 }.detect(&signal).is_empty());
+    }
+
+
+    #[test]
+    fn literal_with_defaults_matches_the_constructor() {
+        let detector = PercentileHotspot { percentile: 50.0, ..Default::default() };
+        assert_eq!(detector.order, HotspotOrder::Index);
+        assert_eq!(detector.detect(&SIGNAL), PercentileHotspot::new(50.0).detect(&SIGNAL));
+        assert_eq!(PercentileHotspot::default().percentile, 80.0);
+    }
+}
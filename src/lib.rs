@@ -1,46 +1,144 @@
-/// Curvature crate: provides tools for analyzing curvature signals,
-/// performing wavelet fusion, managing resonance graphs, and
-/// handling semantic entanglement.
+//! Curvature crate: provides tools for analyzing curvature signals,
+//! performing wavelet fusion, managing resonance graphs, and
+//! handling semantic entanglement.
+//!
+//! Without the default `std` feature only the pure kernels are built (Haar transform,
+//! entropy, hotspot detection and trajectory evaluation), using `alloc` and `libm`.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
 pub mod curvature_signal;
+#[cfg(feature = "std")]
 pub mod entangle;
+#[cfg(feature = "std")]
 pub mod gkernel;
+#[cfg(feature = "std")]
 pub mod resonance;
+#[cfg(feature = "std")]
 pub mod sem_eng;
+#[cfg(feature = "std")]
 pub mod wavelet;
 pub mod hotspot_detector;
 pub mod path_evaluator;
 pub mod core;
+#[cfg(feature = "std")]
 pub mod coherence;
+pub mod error;
+#[cfg(feature = "std")]
+pub mod spectrum;
+#[cfg(feature = "std")]
+pub mod stats;
+#[cfg(feature = "std")]
+pub mod generators;
+pub mod primitives;
+#[cfg(not(any(feature = "std", test)))]
+mod math;
 
 pub use core::PathEvaluator;
-pub use coherence::CoherencePulse;
-pub use curvature_signal::CurvatureSignal;
-pub use entangle::{Coupling, SemanticDomain, SimpleEntangleMap};
-pub use gkernel::{ResonanceNode, ResonanceEdge, GraphKernel};
-pub use hotspot_detector::{HotspotDetector, PercentileHotspot};
-pub use path_evaluator::{PathMetrics, TrajectoryPath};
+#[cfg(feature = "std")]
+pub use coherence::{
+    CoherencePulse, EntropyPulse, EntropyReducer, Recohere, RecoherePulse, ResonanceEntropyPulse,
+    longest_coherent_run,
+};
+pub use error::CurvatureError;
+#[cfg(feature = "std")]
+pub use curvature_signal::{
+    CurvatureSignal, CurvatureSignalBuilder, FrequencyGrid, zero_crossings, dtw_distance,
+    dtw_path,
+};
+#[cfg(feature = "std")]
+pub use entangle::{ComplexCoupling, Coupling, SemanticDomain, SimpleEntangleMap};
+#[cfg(feature = "std")]
+pub use gkernel::{
+    ResonanceNode, ResonanceEdge, GraphKernel, CoherenceWeighting, detect_phase_locking, GraphError,
+};
+pub use hotspot_detector::{
+    HotspotDetector, HotspotOrder, PercentileHotspot, AutoPercentileHotspot, PersistenceHotspot,
+    ZScoreHotspot, chunked_detect,
+};
+pub use path_evaluator::{
+    DistanceMetric, PathMetrics, TrajectoryPath, frechet_distance, minimize_bending_energy,
+    path_shape_score, roughness,
+};
+pub use primitives::{ConvMode, compute_entropy, convolve};
+#[cfg(feature = "std")]
+pub use spectrum::{
+    FilterKind, filter_frequency, instantaneous_frequency, spectral_bandwidth, spectral_centroid,
+    spectral_flatness,
+};
+#[cfg(feature = "std")]
+pub use stats::{SignalStats, signal_stats};
+#[cfg(feature = "std")]
 pub use resonance::{
     Resonance, 
     Position, 
     Gradient, 
     GridField, 
+    FlattenOrder,
     BiologicalField,
+    CoupledFields,
+    WaveField,
+    EntropySource,
     EntangleMap,
     LawSynthEngine,
     ResonanceField,
+    ResonanceValue,
+    spectrogram,
+    local_correlation,
 };
+#[cfg(feature = "std")]
 pub use sem_eng::{
     SemanticEngine, 
+    StepReport,
+    MeanPerturb,
+    belief_disagreement,
+    reseed,
+    ControlApplicator,
+    UnicycleApplicator,
+    HolonomicApplicator,
     VisualEdge, 
     VisualNode, 
     EntanglementOverlay, 
     Synth, 
-    Field};
+    Field,
+    ThreadNoise};
+#[cfg(feature = "std")]
 pub use wavelet::{
     FusionContext, 
+    FusionContextBuilder,
     WaveletBasis, 
     WaveletDecomposition, 
+    CoeffLayout,
     WaveletEngine, 
     WaveletFusionStrategy, 
-    compute_entropy,
-};
\ No newline at end of file
+    level_energy_leakage,
+    haar_transform_multichannel,
+    daubechies_decompose,
+    daubechies_reconstruct,
+    biorthogonal_reconstruct,
+};
+
+/// Exercises the kernels that remain without the `std` feature; run with
+/// `cargo test --no-default-features`.
+#[cfg(all(test, not(feature = "std")))]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn core_kernels_run_without_std() {
+        let signal = [1.0, 3.0, 2.0, 8.0, 0.5, 0.5];
+        let mut coeffs = Vec::new();
+        primitives::haar_transform_into(&signal, &mut coeffs);
+        let restored = primitives::haar_reconstruct(&coeffs);
+        assert!(restored.iter().zip(&signal).all(|(r, s)| (r - s).abs() < 1e-12));
+        assert!(compute_entropy(&coeffs) > 0.0);
+
+        assert_eq!(PercentileHotspot::new(80.0).detect(&signal), vec![1, 3]);
+        let path = TrajectoryPath { dz_dt: 0.0 }.evaluate(&[0.0, 0.0], 1.0);
+        assert_eq!(path.displacement(DistanceMetric::Euclidean), 1.0);
+    }
+}
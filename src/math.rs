@@ -0,0 +1,38 @@
+//! Float math for builds without the `std` feature, where `f64`'s inherent methods are
+//! unavailable.
+//! Not compiled under `std` or in tests, where the inherent methods are available.
+
+pub(crate) trait Float {
+    fn floor(self) -> f64;
+    fn log2(self) -> f64;
+    fn sin(self) -> f64;
+    fn cos(self) -> f64;
+    fn hypot(self, other: f64) -> f64;
+    fn sqrt(self) -> f64;
+}
+
+impl Float for f64 {
+    fn floor(self) -> f64 {
+        libm::floor(self)
+    }
+
+    fn log2(self) -> f64 {
+        libm::log2(self)
+    }
+
+    fn sin(self) -> f64 {
+        libm::sin(self)
+    }
+
+    fn cos(self) -> f64 {
+        libm::cos(self)
+    }
+
+    fn hypot(self, other: f64) -> f64 {
+        libm::hypot(self, other)
+    }
+
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+}
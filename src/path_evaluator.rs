@@ -1,5 +1,12 @@
 /// Path evaluator module: evaluates paths based on curvature signals.
 /// Defines structures and methods for computing path metrics
+#[cfg(feature = "std")]
+use crate::curvature_signal::CurvatureSignal;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
 #[derive(Debug)]
 pub struct PathMetrics {
     pub length: f64,
@@ -8,6 +15,72 @@ pub struct PathMetrics {
     pub y: Vec<f64>,
 }
 
+/// Metric used to measure end-to-end displacement of a path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistanceMetric {
+    Manhattan,
+    Euclidean,
+    Chebyshev,
+}
+
+impl PathMetrics {
+    /// Displacement between the first and last points under the given metric.
+    pub fn displacement(&self, metric: DistanceMetric) -> f64 {
+        let dx = self.x.last().unwrap_or(&0.0) - self.x.first().unwrap_or(&0.0);
+        let dy = self.y.last().unwrap_or(&0.0) - self.y.first().unwrap_or(&0.0);
+        match metric {
+            DistanceMetric::Manhattan => dx.abs() + dy.abs(),
+            DistanceMetric::Euclidean => dx.hypot(dy),
+            DistanceMetric::Chebyshev => dx.abs().max(dy.abs()),
+        }
+    }
+
+    /// Manhattan displacement with per-axis scales, `wx * |dx| + wy * |dy|`, for domains
+    /// whose axes carry different units. Equal unit weights give `manhattan_distance`.
+    pub fn weighted_manhattan(&self, wx: f64, wy: f64) -> f64 {
+        let dx = self.x.last().unwrap_or(&0.0) - self.x.first().unwrap_or(&0.0);
+        let dy = self.y.last().unwrap_or(&0.0) - self.y.first().unwrap_or(&0.0);
+        wx * dx.abs() + wy * dy.abs()
+    }
+
+    /// Recovers a curvature signal from the path points, inverting `TrajectoryPath::evaluate`.
+    /// Assumes the path starts at the origin with zero heading, as `evaluate` does;
+    /// `positions` hold the cumulative arc length of each point.
+    #[cfg(feature = "std")]
+    pub fn to_curvature_signal(&self, dt: f64) -> CurvatureSignal {
+        let mut positions = Vec::with_capacity(self.x.len());
+        let mut values = Vec::with_capacity(self.x.len());
+
+        let (mut px, mut py) = (0.0, 0.0);
+        let mut heading = 0.0;
+        let mut arc = 0.0;
+
+        for (&x, &y) in self.x.iter().zip(&self.y) {
+            let (dx, dy) = (x - px, y - py);
+            let theta = dy.atan2(dx);
+
+            // Wrap the turn into (-pi, pi] so headings crossing the branch cut stay continuous
+            let mut turn = theta - heading;
+            while turn > core::f64::consts::PI {
+                turn -= 2.0 * core::f64::consts::PI;
+            }
+            while turn <= -core::f64::consts::PI {
+                turn += 2.0 * core::f64::consts::PI;
+            }
+
+            arc += dx.hypot(dy);
+            positions.push(arc);
+            values.push(turn / dt);
+
+            heading += turn;
+            px = x;
+            py = y;
+        }
+
+        CurvatureSignal { positions, values }
+    }
+}
+
 pub struct TrajectoryPath {
     pub dz_dt: f64, // optional z-bias
 }
@@ -33,16 +106,267 @@ impl TrajectoryPath {
         }
 
         let length = curvature.len() as f64 * dt;
-        let dx = x.last().unwrap_or(&0.0) - x.first().unwrap_or(&0.0);
-        let dy = y.last().unwrap_or(&0.0) - y.first().unwrap_or(&0.0);
-        let manhattan = dx.abs() + dy.abs();
-
-        PathMetrics {
+        let mut metrics = PathMetrics {
             length,
-            manhattan_distance: manhattan,
+            manhattan_distance: 0.0,
             x,
             y,
+        };
+        metrics.manhattan_distance = metrics.displacement(DistanceMetric::Manhattan);
+        metrics
+    }
+
+    /// Evaluates each curvature array independently with the same settings.
+    /// Parallelized across arrays when the `rayon` feature is enabled.
+    pub fn evaluate_batch(&self, curvatures: &[&[f64]], dt: f64) -> Vec<PathMetrics> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            curvatures.par_iter().map(|k| self.evaluate(k, dt)).collect()
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            curvatures.iter().map(|k| self.evaluate(k, dt)).collect()
+        }
+    }
+}
+
+
+/// Roughness of a curvature profile: the sum of squared first differences
+/// `sum((s[i+1] - s[i])^2)`. Zero for a constant profile; large for jagged ones.
+pub fn roughness(signal: &[f64]) -> f64 {
+    signal.windows(2).fold(0.0, |acc, w| acc + (w[1] - w[0]) * (w[1] - w[0]))
+}
+
+/// Synthesizes the curvature profile of `n` samples that turns the heading from
+/// `start_heading` to `end_heading` (so `sum(k) * dt` equals the difference) with the least
+/// bending energy `sum(k^2) * dt`: a discrete Euler elastica, found by projected gradient
+/// descent from a linear curvature ramp. With only heading constraints the optimum is the
+/// constant-curvature arc. Returns an empty vec if `n` is 0 or `dt` is not positive.
+pub fn minimize_bending_energy(
+    n: usize,
+    start_heading: f64,
+    end_heading: f64,
+    dt: f64,
+) -> Vec<f64> {
+    if n == 0 || dt <= 0.0 || dt.is_nan() {
+        return Vec::new();
+    }
+
+    let turn = end_heading - start_heading;
+    let nf = n as f64;
+    // Linear ramp k_i proportional to i + 1, scaled to the required total turn
+    let mut curvature: Vec<f64> = (0..n)
+        .map(|i| 2.0 * turn * (i + 1) as f64 / (nf * (nf + 1.0) * dt))
+        .collect();
+
+    let rate = 0.25 / dt;
+    for _ in 0..1000 {
+        // Gradient of the energy is 2 k dt; projecting out its mean keeps the total turn fixed
+        let mean = curvature.iter().sum::<f64>() / nf;
+        let mut change: f64 = 0.0;
+        for k in &mut curvature {
+            let step = rate * 2.0 * (*k - mean) * dt;
+            *k -= step;
+            change = change.max(step.abs());
+        }
+        if change < 1e-12 {
+            break;
+        }
+    }
+
+    curvature
+}
+
+/// Discrete Fréchet distance between the point sequences of two paths: the shortest
+/// "leash" that lets both be walked in order, each walker only moving forward.
+/// Zero for two empty paths and infinite if only one is empty.
+pub fn frechet_distance(a: &PathMetrics, b: &PathMetrics) -> f64 {
+    let point = |p: &PathMetrics, i: usize| (p.x[i], p.y[i]);
+    let (n, m) = (a.x.len().min(a.y.len()), b.x.len().min(b.y.len()));
+    if n == 0 || m == 0 {
+        return if n == m { 0.0 } else { f64::INFINITY };
+    }
+
+    // Rolling row of the coupling table: row[j] is the distance for a[..=i], b[..=j]
+    let mut row = Vec::with_capacity(m);
+    for i in 0..n {
+        let (ax, ay) = point(a, i);
+        let mut diagonal = f64::INFINITY; // previous row's entry at j - 1
+        for j in 0..m {
+            let (bx, by) = point(b, j);
+            let d = (ax - bx).hypot(ay - by);
+            let reach = match (i, j) {
+                (0, 0) => d,
+                (0, _) => row[j - 1],
+                (_, 0) => row[0],
+                _ => diagonal.min(row[j]).min(row[j - 1]),
+            };
+            let value = d.max(reach);
+            if i == 0 {
+                row.push(value);
+            } else {
+                diagonal = row[j];
+                row[j] = value;
+            }
         }
     }
+    row[m - 1]
+}
+
+/// Scores how closely `generated` follows `target` in `(0, 1]`, 1 for a perfect match:
+/// `1 / (1 + F + E)` with `F` the Fréchet distance and `E` the distance between the end
+/// points, both divided by the target's polyline length (or 1 if that is zero).
+/// Paths with no points only match each other.
+pub fn path_shape_score(generated: &PathMetrics, target: &PathMetrics) -> f64 {
+    let frechet = frechet_distance(generated, target);
+    if !frechet.is_finite() {
+        return 0.0;
+    }
+    let end = |p: &PathMetrics| p.x.last().zip(p.y.last()).map(|(&x, &y)| (x, y));
+    let endpoint = match (end(generated), end(target)) {
+        (Some((gx, gy)), Some((tx, ty))) => (gx - tx).hypot(gy - ty),
+        _ => 0.0,
+    };
+
+    let scale: f64 = target
+        .x
+        .windows(2)
+        .zip(target.y.windows(2))
+        .map(|(x, y)| (x[1] - x[0]).hypot(y[1] - y[0]))
+        .sum();
+    let scale = if scale > 0.0 { scale } else { 1.0 };
+    1.0 / (1.0 + (frechet + endpoint) / scale)
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn displacement_metrics_on_a_diagonal_move() {
+        let path = PathMetrics {
+            length: 5.0,
+            manhattan_distance: 7.0,
+            x: vec![1.0, 2.0, 4.0],
+            y: vec![-1.0, 0.0, 3.0],
+        };
+        assert_eq!(path.displacement(DistanceMetric::Manhattan), 7.0);
+        assert_eq!(path.displacement(DistanceMetric::Euclidean), 5.0);
+        assert_eq!(path.displacement(DistanceMetric::Chebyshev), 4.0);
+    }
+
+    #[test]
+    fn evaluate_batch_matches_individual_evaluations() {
+        let curvatures: [&[f64]; 3] = [&[0.1, 0.2, 0.3], &[], &[-0.5; 7]];
+        let path = TrajectoryPath { dz_dt: 0.0 };
+        let batch = path.evaluate_batch(&curvatures, 0.5);
+        assert_eq!(batch.len(), curvatures.len());
+        for (metrics, curvature) in batch.iter().zip(curvatures) {
+            let single = path.evaluate(curvature, 0.5);
+            assert_eq!((&metrics.x, &metrics.y), (&single.x, &single.y));
+            assert_eq!(metrics.length, single.length);
+            assert_eq!(metrics.manhattan_distance, single.manhattan_distance);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn path_round_trips_to_its_curvature_signal() {
+        let curvature: Vec<f64> = (0..50).map(|i| 0.5 * (i as f64 / 8.0).sin()).collect();
+        let path = TrajectoryPath { dz_dt: 0.0 }.evaluate(&curvature, 0.1);
+        let signal = path.to_curvature_signal(0.1);
+
+        assert_eq!(signal.values.len(), curvature.len());
+        for (i, (k, expected)) in signal.values.iter().zip(&curvature).enumerate() {
+            assert!((k - expected).abs() < 1e-9, "sample {i}: {k} != {expected}");
+            assert!((signal.positions[i] - 0.1 * (i + 1) as f64).abs() < 1e-9);
+        }
+    }
+
+
+    #[test]
+    fn minimum_energy_profile_beats_a_linear_ramp() {
+        let (n, dt, turn) = (20, 0.1, core::f64::consts::FRAC_PI_2);
+        let energy = |k: &[f64]| k.iter().map(|k| k * k).sum::<f64>() * dt;
+        let nf = n as f64;
+        let ramp: Vec<f64> =
+            (0..n).map(|i| 2.0 * turn * (i + 1) as f64 / (nf * (nf + 1.0) * dt)).collect();
+
+        let curvature = minimize_bending_energy(n, 0.3, 0.3 + turn, dt);
+        assert_eq!(curvature.len(), n);
+        assert!((curvature.iter().sum::<f64>() * dt - turn).abs() < 1e-9);
+        assert!((ramp.iter().sum::<f64>() * dt - turn).abs() < 1e-9);
+        assert!(energy(&curvature) < energy(&ramp));
+        // The optimum is the constant-curvature arc
+        let arc = turn / (nf * dt);
+        assert!(curvature.iter().all(|k| (k - arc).abs() < 1e-6));
+    }
+
+    #[test]
+    fn bending_energy_rejects_degenerate_sampling() {
+        assert!(minimize_bending_energy(0, 0.0, 1.0, 0.1).is_empty());
+        assert!(minimize_bending_energy(5, 0.0, 1.0, 0.0).is_empty());
+        assert!(minimize_bending_energy(5, 0.0, 1.0, f64::NAN).is_empty());
+    }
+
+
+    #[test]
+    fn weighted_manhattan_scales_each_axis() {
+        let path = TrajectoryPath { dz_dt: 0.0 }.evaluate(&[0.3, -0.1, 0.4, 0.2, 0.0], 0.5);
+        let unit = path.weighted_manhattan(1.0, 1.0);
+        assert!((unit - path.manhattan_distance).abs() < 1e-12);
+
+        let dx = (path.x[4] - path.x[0]).abs();
+        let dy = (path.y[4] - path.y[0]).abs();
+        assert!((path.weighted_manhattan(2.0, 0.5) - (2.0 * dx + 0.5 * dy)).abs() < 1e-12);
+        assert!((path.weighted_manhattan(3.0, 3.0) - 3.0 * unit).abs() < 1e-12);
+        assert!((path.weighted_manhattan(0.0, 1.0) - dy).abs() < 1e-12);
+    }
+
+
+    #[test]
+    fn roughness_is_zero_when_flat_and_large_for_a_sawtooth() {
+        assert_eq!(roughness(&[0.7; 16]), 0.0);
+        assert_eq!(roughness(&[]), 0.0);
+        assert_eq!(roughness(&[3.0]), 0.0);
+
+        let sawtooth: Vec<f64> = (0..16).map(|i| (i % 4) as f64).collect();
+        let ramp: Vec<f64> = (0..16).map(|i| i as f64 * 0.2).collect();
+        // Twelve unit rises and three drops of 3 between the teeth
+        assert_eq!(roughness(&sawtooth), 12.0 + 3.0 * 9.0);
+        assert!((roughness(&ramp) - 15.0 * 0.04).abs() < 1e-12);
+        assert!(roughness(&sawtooth) > 50.0 * roughness(&ramp));
+    }
+
+
+    #[test]
+    fn a_path_scores_one_against_itself_and_less_when_shifted() {
+        let path = TrajectoryPath { dz_dt: 0.0 }.evaluate(&[0.2, 0.4, -0.1, 0.3, 0.0, -0.2], 1.0);
+        assert_eq!(path_shape_score(&path, &path), 1.0);
+
+        let shifted = |d: f64| PathMetrics {
+            length: path.length,
+            manhattan_distance: path.manhattan_distance,
+            x: path.x.clone(),
+            y: path.y.iter().map(|y| y + d).collect(),
+        };
+        let length: f64 = path
+            .x
+            .windows(2)
+            .zip(path.y.windows(2))
+            .map(|(x, y)| (x[1] - x[0]).hypot(y[1] - y[0]))
+            .sum();
+        // A rigid shift by d moves both the Fréchet leash and the end point by d
+        let near = path_shape_score(&shifted(0.1), &path);
+        assert!((near - 1.0 / (1.0 + 0.2 / length)).abs() < 1e-12);
+        assert!(path_shape_score(&shifted(1.0), &path) < near);
+
+        let empty = PathMetrics { length: 0.0, manhattan_distance: 0.0, x: vec![], y: vec![] };
+        assert_eq!(path_shape_score(&empty, &empty), 1.0);
+        assert_eq!(path_shape_score(&empty, &path), 0.0);
+        assert_eq!(path_shape_score(&path, &empty), 0.0);
+    }
+}
@@ -0,0 +1,160 @@
+//! Primitives module: allocation-only signal kernels shared by the wavelet code.
+//! Builds without the standard library when the `std` feature is off.
+use core::f64::consts::FRAC_1_SQRT_2;
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Single-level orthonormal Haar transform writing into `out`, so its allocation can be
+/// reused across calls. Output is grouped: approximation coefficients first, then detail
+/// coefficients. An odd-length signal is padded by repeating its last sample, so `out` then
+/// holds `n + 1` coefficients and the final detail coefficient is zero.
+pub fn haar_transform_into(signal: &[f64], out: &mut Vec<f64>) {
+    let half = signal.len().div_ceil(2);
+    out.clear();
+    out.resize(2 * half, 0.0);
+
+    for (step, pair) in signal.chunks(2).enumerate() {
+        let a = pair[0];
+        let b = pair.get(1).copied().unwrap_or(a);
+        out[step] = (a + b) * FRAC_1_SQRT_2;
+        out[half + step] = (b - a) * FRAC_1_SQRT_2;
+    }
+}
+
+/// Inverts `haar_transform_into` for grouped coefficients. For a padded odd-length input the
+/// repeated last sample reappears at the end of the output.
+pub fn haar_reconstruct(coeffs: &[f64]) -> Vec<f64> {
+    let half = coeffs.len() / 2;
+    let mut out = Vec::with_capacity(coeffs.len());
+    for (a, d) in coeffs[..half].iter().zip(&coeffs[half..]) {
+        out.push((a - d) * FRAC_1_SQRT_2);
+        out.push((a + d) * FRAC_1_SQRT_2);
+    }
+    out
+}
+
+/// Shannon entropy (in bits) of the coefficients' normalised magnitudes.
+pub fn compute_entropy(coeffs: &[f64]) -> f64 {
+    let norm: f64 = coeffs.iter().map(|c| c.abs()).sum();
+    coeffs
+        .iter()
+        .map(|c| {
+            let p = c.abs() / norm;
+            if p > 0.0 {
+                -p * p.log2()
+            } else {
+                0.0
+            }
+        })
+        .sum()
+}
+
+/// Output extent of `convolve`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvMode {
+    /// Every overlap of signal and kernel: `n + m - 1` samples.
+    Full,
+    /// The centre of `Full`, with the signal's length `n`.
+    Same,
+    /// Only positions where the kernel fits entirely inside the signal: `n - m + 1` samples
+    /// (none if the kernel is longer than the signal).
+    Valid,
+}
+
+/// Discrete linear convolution of `signal` with an FIR `kernel`, zero outside the signal.
+/// Returns an empty vec if either input is empty.
+pub fn convolve(signal: &[f64], kernel: &[f64], mode: ConvMode) -> Vec<f64> {
+    let (n, m) = (signal.len(), kernel.len());
+    if n == 0 || m == 0 {
+        return Vec::new();
+    }
+
+    let (start, len) = match mode {
+        ConvMode::Full => (0, n + m - 1),
+        ConvMode::Same => ((m - 1) / 2, n),
+        ConvMode::Valid => (m - 1, (n + 1).saturating_sub(m)),
+    };
+
+    (start..start + len)
+        .map(|k| {
+            // Sum over i with 0 <= i < n and 0 <= k - i < m
+            let first = (k + 1).saturating_sub(m);
+            let last = k.min(n - 1);
+            (first..=last).map(|i| signal[i] * kernel[k - i]).sum()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn energy(xs: &[f64]) -> f64 {
+        xs.iter().map(|x| x * x).sum()
+    }
+
+    #[test]
+    fn haar_into_preserves_energy_on_even_input() {
+        let signal = [4.0, 2.0, -1.0, 3.0, 0.5, 0.5];
+        let mut out = Vec::new();
+        haar_transform_into(&signal, &mut out);
+        assert_eq!(out.len(), signal.len());
+        assert!((energy(&out) - energy(&signal)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn haar_into_pads_odd_input_instead_of_overwriting() {
+        let signal = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let mut out = Vec::new();
+        haar_transform_into(&signal, &mut out);
+        assert_eq!(out.len(), 6);
+        assert_eq!(out[5], 0.0);
+        // The padded pair (5, 5) contributes 50 where the lone last sample held 25
+        assert!((energy(&out) - 80.0).abs() < 1e-12);
+        let recon = haar_reconstruct(&out);
+        for (r, s) in recon.iter().zip(signal.iter().chain([5.0].iter())) {
+            assert!((r - s).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn haar_into_reuses_the_buffer() {
+        let mut out = vec![9.0; 32];
+        let capacity = out.capacity();
+        haar_transform_into(&[1.0, 1.0], &mut out);
+        assert_eq!(out.len(), 2);
+        assert_eq!(out.capacity(), capacity);
+        assert!((out[0] - 2.0 * FRAC_1_SQRT_2).abs() < 1e-12);
+        assert_eq!(out[1], 0.0);
+    }
+
+    #[test]
+    fn haar_round_trip() {
+        let signal = [0.3, -1.2, 4.5, 2.0, 0.0, 7.25, -3.5, 1.0];
+        let mut out = Vec::new();
+        haar_transform_into(&signal, &mut out);
+        let recon = haar_reconstruct(&out);
+        for (r, s) in recon.iter().zip(&signal) {
+            assert!((r - s).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn convolve_modes_match_hand_computed_results() {
+        let signal = [1.0, 2.0, 3.0, 4.0];
+        let derivative = [1.0, 0.0, -1.0];
+        let full = convolve(&signal, &derivative, ConvMode::Full);
+        assert_eq!(full, [1.0, 2.0, 2.0, 2.0, -3.0, -4.0]);
+        assert_eq!(convolve(&signal, &derivative, ConvMode::Same), [2.0, 2.0, 2.0, -3.0]);
+        assert_eq!(convolve(&signal, &derivative, ConvMode::Valid), [2.0, 2.0]);
+
+        // Even kernels take the earlier of the two centres
+        assert_eq!(convolve(&signal, &[1.0, 1.0], ConvMode::Same), [1.0, 3.0, 5.0, 7.0]);
+        assert!(convolve(&signal, &[1.0; 5], ConvMode::Valid).is_empty());
+        assert!(convolve(&[], &derivative, ConvMode::Full).is_empty());
+    }
+}
@@ -3,9 +3,13 @@
 /// entanglement maps, and law synthesis engines.
 use crate::wavelet::{
     FusionContext, WaveletBasis, WaveletDecomposition, 
-    WaveletEngine, WaveletFusionStrategy, compute_entropy
+    WaveletEngine, WaveletFusionStrategy, compute_entropy, remove_mean
 };
+use crate::entangle::{SemanticDomain, SimpleEntangleMap};
+use crate::error::CurvatureError;
+use crate::spectrum::{bin_frequency, magnitude_spectrum};
 use coheron::traits::BeliefTensor;
+use std::cell::OnceCell;
 
 
 #[derive(Debug, Clone)]
@@ -14,6 +18,50 @@ pub struct Resonance {
     pub frequency: f64,
 }
 
+/// Uniform view over the resonance representations used by fields, so generic
+/// code can read amplitude and frequency whether a field reports `Resonance` or `f64`.
+pub trait ResonanceValue {
+    fn amplitude(&self) -> f64;
+    fn frequency(&self) -> f64;
+
+    /// Linear mix `self * (1 - weight) + other * weight`.
+    fn blend(&self, other: &Self, weight: f64) -> Self
+    where
+        Self: Sized;
+}
+
+impl ResonanceValue for Resonance {
+    fn amplitude(&self) -> f64 {
+        self.amplitude
+    }
+
+    fn frequency(&self) -> f64 {
+        self.frequency
+    }
+
+    fn blend(&self, other: &Self, weight: f64) -> Self {
+        Resonance {
+            amplitude: self.amplitude * (1.0 - weight) + other.amplitude * weight,
+            frequency: self.frequency * (1.0 - weight) + other.frequency * weight,
+        }
+    }
+}
+
+/// Scalar resonance, as used by `BiologicalField`, is its own amplitude and carries no frequency.
+impl ResonanceValue for f64 {
+    fn amplitude(&self) -> f64 {
+        *self
+    }
+
+    fn frequency(&self) -> f64 {
+        0.0
+    }
+
+    fn blend(&self, other: &Self, weight: f64) -> Self {
+        self * (1.0 - weight) + other * weight
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Gradient {
     pub direction: [f64; 2],
@@ -26,10 +74,177 @@ pub struct Position {
     pub y: f64,
 }
 
+/// Errors raised when querying a field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldError {
+    OutOfBounds { x: f64, y: f64 },
+}
+
+impl std::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldError::OutOfBounds { x, y } => write!(f, "position ({x}, {y}) is outside the field"),
+        }
+    }
+}
+
+impl std::error::Error for FieldError {}
+
+/// Callback receiving the `(x, y)` cell and its new coherence when it crosses a threshold.
+pub type ThresholdCallback = Box<dyn FnMut(usize, usize, f64)>;
+
+/// Order in which `GridField::signal` flattens the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FlattenOrder {
+    /// Row by row: `map[0][0], map[0][1], ...`
+    #[default]
+    RowMajor,
+    /// Column by column: `map[0][0], map[1][0], ...`
+    ColumnMajor,
+}
+
+/// 2D coherence grid. Build it with `GridField::new`: the threshold callback is private
+/// state, so struct literals no longer compile (a breaking change from the all-public
+/// struct). For the same reason `coherence_map`, `flatten_order`, `width` and `height`
+/// are now read (and the first two written) through methods, which keep `signal()` and
+/// the dimensions in sync with the grid.
 pub struct GridField {
-    pub coherence_map: Vec<Vec<f64>>, // 2D grid
-    pub width: usize,
-    pub height: usize,
+    coherence_map: Vec<Vec<f64>>, // 2D grid
+    width: usize,
+    height: usize,
+    /// Scales resonance amplitude into the coherence delta applied by `propagate`.
+    pub propagation_gain: f64,
+    /// Optional 3x3 stencil spreading the delta around the affected cell
+    /// (`kernel[dy + 1][dx + 1]`); `None` updates only the cell itself.
+    pub propagation_kernel: Option<[[f64; 3]; 3]>,
+    flatten_order: FlattenOrder,
+    threshold_callback: Option<(f64, ThresholdCallback)>,
+    /// Flattened grid behind `signal()`, built on first use after a change.
+    flat: OnceCell<Vec<f64>>,
+}
+
+impl GridField {
+    pub fn new(coherence_map: Vec<Vec<f64>>) -> Self {
+        let height = coherence_map.len();
+        let width = coherence_map.first().map_or(0, |row| row.len());
+        GridField {
+            coherence_map,
+            width,
+            height,
+            propagation_gain: 0.01,
+            propagation_kernel: None,
+            flatten_order: FlattenOrder::default(),
+            threshold_callback: None,
+            flat: OnceCell::new(),
+        }
+    }
+
+    /// The 2D grid, indexed `[y][x]`.
+    pub fn coherence_map(&self) -> &[Vec<f64>] {
+        &self.coherence_map
+    }
+
+    /// Number of cells in the first row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Replaces the grid, updating `width` and `height` to match.
+    pub fn set_coherence_map(&mut self, coherence_map: Vec<Vec<f64>>) {
+        self.height = coherence_map.len();
+        self.width = coherence_map.first().map_or(0, |row| row.len());
+        self.coherence_map = coherence_map;
+        self.flat.take();
+    }
+
+    /// Sets the coherence at `(x, y)`; returns false (changing nothing) outside the grid.
+    pub fn set_cell(&mut self, x: usize, y: usize, coherence: f64) -> bool {
+        let Some(cell) = self.coherence_map.get_mut(y).and_then(|row| row.get_mut(x)) else {
+            return false;
+        };
+        *cell = coherence;
+        self.flat.take();
+        true
+    }
+
+    /// Flattening used by `signal()`.
+    pub fn flatten_order(&self) -> FlattenOrder {
+        self.flatten_order
+    }
+
+    pub fn set_flatten_order(&mut self, order: FlattenOrder) {
+        self.flatten_order = order;
+        self.flat.take();
+    }
+
+    fn flatten(&self) -> Vec<f64> {
+        let (width, height) = (self.width, self.height);
+        let cell = |x: usize, y: usize| self.coherence_map.get(y).and_then(|row| row.get(x));
+        match self.flatten_order {
+            FlattenOrder::RowMajor => (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .filter_map(|(x, y)| cell(x, y).copied())
+                .collect(),
+            FlattenOrder::ColumnMajor => (0..width)
+                .flat_map(|x| (0..height).map(move |y| (x, y)))
+                .filter_map(|(x, y)| cell(x, y).copied())
+                .collect(),
+        }
+    }
+
+    /// Registers a callback fired during `propagate` whenever a cell's coherence
+    /// crosses `threshold` in either direction. Replaces any previous callback.
+    pub fn set_threshold_callback(&mut self, threshold: f64, cb: ThresholdCallback) {
+        self.threshold_callback = Some((threshold, cb));
+    }
+
+    /// Returns true when every cell differs from the same cell of `prev` by at most `tol`,
+    /// e.g. a snapshot taken before the last `propagate`. Grids of different dimensions
+    /// (or holding NaN) never count as converged.
+    pub fn has_converged(&self, prev: &GridField, tol: f64) -> bool {
+        let same_shape = self.coherence_map.len() == prev.coherence_map.len()
+            && self
+                .coherence_map
+                .iter()
+                .zip(&prev.coherence_map)
+                .all(|(a, b)| a.len() == b.len());
+        same_shape
+            && self
+                .coherence_map
+                .iter()
+                .flatten()
+                .zip(prev.coherence_map.iter().flatten())
+                .all(|(a, b)| (a - b).abs() <= tol)
+    }
+
+    /// Like `observe`, but reports positions outside the grid (or non-finite ones)
+    /// as `FieldError::OutOfBounds` (wrapped in `CurvatureError::Field`) instead of clamping
+    /// them onto the edge.
+    pub fn try_observe(&self, pos: &Position) -> Result<Gradient, CurvatureError> {
+        let out_of_bounds = || CurvatureError::from(FieldError::OutOfBounds { x: pos.x, y: pos.y });
+        if !(pos.x >= 0.0 && pos.y >= 0.0 && pos.x.is_finite() && pos.y.is_finite()) {
+            return Err(out_of_bounds());
+        }
+
+        let x = pos.x as usize;
+        let y = pos.y as usize;
+        // Rows may differ in length, so every cell is looked up rather than indexed
+        let cell = |x: usize, y: usize| self.coherence_map.get(y).and_then(|row| row.get(x));
+
+        let center = *cell(x, y).ok_or_else(out_of_bounds)?;
+        let dx = cell(x.saturating_sub(1), y).map_or(0.0, |v| v - center);
+        let dy = cell(x, y.saturating_sub(1)).map_or(0.0, |v| v - center);
+
+        Ok(Gradient {
+            direction: [dx, dy],
+            magnitude: (dx.powi(2) + dy.powi(2)).sqrt(),
+        })
+    }
 }
 
 
@@ -42,9 +257,55 @@ pub trait ResonanceField {
     fn compute_resonance(&self, position: &Self::Position) -> Self::Resonance;
     fn propagate(&mut self, position: &Self::Position, influence: &Self::Resonance);
 
+    /// Re-seeds any noise source the field draws from; called by `SemanticEngine::reset`.
+    /// Deterministic fields keep the default no-op.
+    fn reseed(&mut self, _seed: u64) {}
+
+    /// Samples the resonance at each position along a trajectory.
+    fn resonance_along(&self, positions: &[Self::Position]) -> Vec<Self::Resonance> {
+        positions.iter().map(|p| self.compute_resonance(p)).collect()
+    }
+
+    /// Returns the resonance amplitude at each position along a trajectory.
+    fn amplitude_envelope(&self, positions: &[Self::Position]) -> Vec<f64>
+    where
+        Self::Resonance: ResonanceValue,
+    {
+        self.resonance_along(positions)
+            .iter()
+            .map(ResonanceValue::amplitude)
+            .collect()
+    }
+
+    /// Dominant frequency, in cycles per sample, of the resonance amplitude along a path of
+    /// evenly spaced positions: the highest non-DC bin of its spectrum after removing the mean.
+    /// Returns 0.0 for fewer than two positions or a constant envelope.
+    fn dominant_frequency(&self, positions: &[Self::Position]) -> f64
+    where
+        Self::Resonance: ResonanceValue,
+    {
+        let (centred, _) = remove_mean(&self.amplitude_envelope(positions));
+        let spectrum = magnitude_spectrum(&centred);
+        let peak = spectrum
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter(|(_, m)| **m > 1e-9)
+            .fold(None, |best: Option<(usize, f64)>, (k, &m)| match best {
+                Some((_, best_m)) if best_m >= m => best,
+                _ => Some((k, m)),
+            });
+        peak.map_or(0.0, |(k, _)| bin_frequency(k, centred.len(), 1.0))
+    }
+
     /// Returns the raw signal representing the resonance field.
     fn signal(&self) -> &[f64];
 
+    /// Length of `signal()`; override when it is known without materializing the signal.
+    fn signal_len(&self) -> usize {
+        self.signal().len()
+    }
+
     /// Returns the semantic domain label (e.g. "quantum", "biological").
     fn domain_label(&self) -> &str;
 
@@ -60,7 +321,21 @@ pub trait ResonanceField {
         engine.fuse(self.signal(), &self.fusion_context(), level)
     }
 
+    /// Like `fused_spectrum`, but skips the fusion and returns `None` for inactive fields,
+    /// whose dominant amplitude (the peak `|value|` of `signal()`) is below `min_amplitude`.
+    fn fused_spectrum_gated<F: WaveletFusionStrategy>(
+        &self,
+        engine: &WaveletEngine<F>,
+        level: usize,
+        min_amplitude: f64,
+    ) -> Option<WaveletDecomposition> {
+        let peak = self.signal().iter().fold(0.0_f64, |peak, v| peak.max(v.abs()));
+        (peak >= min_amplitude).then(|| self.fused_spectrum(engine, level))
+    }
+
     /// Optionally returns the dominant basis for this field.
+    /// NaN scores are skipped and ties go to the basis listed first in the engine's set;
+    /// returns `None` when no basis has a usable score.
     fn dominant_basis<F: WaveletFusionStrategy>(
         &self,
         engine: &WaveletEngine<F>,
@@ -68,7 +343,11 @@ pub trait ResonanceField {
         engine
             .score_bases(self.signal(), &self.fusion_context())
             .into_iter()
-            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .filter(|(_, score)| !score.is_nan())
+            .fold(None, |best: Option<(WaveletBasis, f64)>, (basis, score)| match best {
+                Some((_, best_score)) if best_score >= score => best,
+                _ => Some((basis, score)),
+            })
             .map(|(basis, _)| basis)
     }
 }
@@ -120,18 +399,16 @@ impl ResonanceField for GridField {
     type Gradient = Gradient;
     type Resonance = Resonance;
 
+    /// Clamps the position onto the grid (onto its own row, for ragged grids) before
+    /// observing; panics only on an empty grid or row.
     fn observe(&self, pos: &Position) -> Gradient {
-        let x = pos.x as usize;
-        let y = pos.y as usize;
-
-        let center = self.coherence_map[y][x];
-        let dx = self.coherence_map[y][x.saturating_sub(1)] - center;
-        let dy = self.coherence_map[y.saturating_sub(1)][x] - center;
-
-        Gradient {
-            direction: [dx, dy],
-            magnitude: (dx.powi(2) + dy.powi(2)).sqrt(),
-        }
+        let y = pos.y.max(0.0).min(self.height.saturating_sub(1) as f64);
+        let row_len = self.coherence_map.get(y as usize).map_or(0, |row| row.len());
+        let clamped = Position {
+            x: pos.x.max(0.0).min(row_len.min(self.width).saturating_sub(1) as f64),
+            y,
+        };
+        self.try_observe(&clamped).expect("cannot observe an empty grid")
     }
 
     fn compute_resonance(&self, pos: &Position) -> Resonance {
@@ -145,16 +422,50 @@ impl ResonanceField for GridField {
     fn propagate(&mut self, pos: &Position, influence: &Resonance) {
         let x = pos.x as usize;
         let y = pos.y as usize;
-        let delta = influence.amplitude * 0.01;
+        let delta = influence.amplitude * self.propagation_gain;
 
-        self.coherence_map[y][x] += delta;
+        // Offsets are stored shifted by one (`kernel[dy + 1][dx + 1]`), and both paths go
+        // through the same bounds filter so off-grid positions change nothing
+        let offsets: Vec<(usize, usize, f64)> = match self.propagation_kernel {
+            None => vec![(1, 1, 1.0)],
+            Some(kernel) => (0..3)
+                .flat_map(|ky| (0..3).map(move |kx| (kx, ky, kernel[ky][kx])))
+                .collect(),
+        };
+        let stencil: Vec<(usize, usize, f64)> = offsets
+            .into_iter()
+            .filter_map(|(kx, ky, weight)| {
+                let cx = x.checked_add(kx)?.checked_sub(1)?;
+                let cy = y.checked_add(ky)?.checked_sub(1)?;
+                let inside = cx < self.width
+                    && cy < self.height
+                    && self.coherence_map.get(cy).is_some_and(|row| cx < row.len());
+                inside.then_some((cx, cy, weight))
+            })
+            .collect();
+
+        for (cx, cy, weight) in stencil {
+            let before = self.coherence_map[cy][cx];
+            let after = before + delta * weight;
+            self.coherence_map[cy][cx] = after;
+
+            if let Some((threshold, cb)) = self.threshold_callback.as_mut()
+                && (before < *threshold) != (after < *threshold) {
+                    cb(cx, cy, after);
+                }
+        }
+
+        self.flat.take();
     }
 
+    /// The whole grid flattened in `flatten_order`, rebuilt lazily after a change.
     fn signal(&self) -> &[f64] {
-        // Flatten the 2D coherence_map into a 1D slice for signal
-        // This is a simple implementation; you may want to adjust as needed
-        // For now, return the first row as a slice
-        self.coherence_map.first().map(|row| row.as_slice()).unwrap_or(&[])
+        self.flat.get_or_init(|| self.flatten())
+    }
+
+    /// Counts the cells `flatten` would visit without building the flattened grid.
+    fn signal_len(&self) -> usize {
+        self.coherence_map.iter().take(self.height).map(|row| row.len().min(self.width)).sum()
     }
 
     fn domain_label(&self) -> &str {
@@ -166,21 +477,96 @@ impl ResonanceField for GridField {
     }
 }
 
-fn _init_field(width: usize, height: usize) -> GridField {
-    let coherence_map = vec![vec![0.5; width]; height];
-    GridField {
-        coherence_map,
-        width,
-        height,
+/// Computes a time-frequency magnitude matrix over Hann-windowed frames.
+/// Each row is one frame (`window / 2 + 1` frequency bins); frames start every `hop` samples.
+pub fn spectrogram(signal: &[f64], window: usize, hop: usize) -> Vec<Vec<f64>> {
+    if window == 0 || hop == 0 || signal.len() < window {
+        return vec![];
+    }
+
+    let hann: Vec<f64> = (0..window)
+        .map(|i| 0.5 - 0.5 * (2.0 * std::f64::consts::PI * i as f64 / window as f64).cos())
+        .collect();
+
+    (0..=signal.len() - window)
+        .step_by(hop)
+        .map(|start| {
+            let frame: Vec<f64> = signal[start..start + window]
+                .iter()
+                .zip(&hann)
+                .map(|(x, w)| x * w)
+                .collect();
+            magnitude_spectrum(&frame)
+        })
+        .collect()
+}
+
+/// Pearson correlation of `a` and `b` within each sliding window of `window` samples,
+/// one value per window position (e.g. a `BiologicalField`'s resonance against its curvature).
+/// Windows where either input is constant yield 0.0.
+pub fn local_correlation(a: &[f64], b: &[f64], window: usize) -> Vec<f64> {
+    let n = a.len().min(b.len());
+    if window < 2 || n < window {
+        return vec![];
     }
+
+    (0..=n - window)
+        .map(|start| {
+            let (wa, wb) = (&a[start..start + window], &b[start..start + window]);
+            let mean_a = wa.iter().sum::<f64>() / window as f64;
+            let mean_b = wb.iter().sum::<f64>() / window as f64;
+            let (mut cov, mut var_a, mut var_b) = (0.0, 0.0, 0.0);
+            for (x, y) in wa.iter().zip(wb) {
+                cov += (x - mean_a) * (y - mean_b);
+                var_a += (x - mean_a).powi(2);
+                var_b += (y - mean_b).powi(2);
+            }
+            let denom = (var_a * var_b).sqrt();
+            if denom > 0.0 { cov / denom } else { 0.0 }
+        })
+        .collect()
 }
 
+fn _init_field(width: usize, height: usize) -> GridField {
+    GridField::new(vec![vec![0.5; width]; height])
+}
+
+
+/// Selects which profile feeds `FusionContext::domain_entropy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EntropySource {
+    #[default]
+    Signal,
+    Resonance,
+    Curvature,
+}
 
+#[derive(Default)]
 pub struct BiologicalField {
     pub signal: Vec<f64>,
     pub tags: Vec<String>,
     pub resonance: Vec<f64>,
     pub curvature: Vec<f64>,
+    /// Which profile feeds `FusionContext::domain_entropy`; defaults to the signal.
+    pub entropy_source: EntropySource,
+}
+
+impl BiologicalField {
+    pub fn new(signal: Vec<f64>, tags: Vec<String>, resonance: Vec<f64>, curvature: Vec<f64>) -> Self {
+        BiologicalField {
+            signal,
+            tags,
+            resonance,
+            curvature,
+            entropy_source: EntropySource::default(),
+        }
+    }
+
+    /// Sets which profile feeds `FusionContext::domain_entropy`.
+    pub fn with_entropy_source(mut self, entropy_source: EntropySource) -> Self {
+        self.entropy_source = entropy_source;
+        self
+    }
 }
 
 impl ResonanceField for BiologicalField {
@@ -192,13 +578,22 @@ impl ResonanceField for BiologicalField {
         &self.signal
     }
 
+    fn signal_len(&self) -> usize {
+        self.signal.len()
+    }
+
     fn domain_label(&self) -> &str {
         "biological"
     }
 
     fn fusion_context(&self) -> FusionContext {
+        let entropy_input = match self.entropy_source {
+            EntropySource::Signal => &self.signal,
+            EntropySource::Resonance => &self.resonance,
+            EntropySource::Curvature => &self.curvature,
+        };
         FusionContext {
-            domain_entropy: compute_entropy(&self.signal),
+            domain_entropy: compute_entropy(entropy_input),
             resonance_profile: Some(self.resonance.clone()),
             semantic_tags: self.tags.clone(),
             coherence_map: None,
@@ -220,4 +615,640 @@ impl ResonanceField for BiologicalField {
             *r += *influence;
         }
     }
-}
\ No newline at end of file
+}
+/// One-dimensional field evolving under the damped wave equation
+/// `u_tt + 2 * damping * u_t = wave_speed^2 * u_xx`, advanced one leapfrog step per
+/// `propagate` call with fixed (zero) boundaries. `wave_speed` is in cells per step
+/// and must stay at or below 1.0 for the scheme to be stable.
+pub struct WaveField {
+    pub signal: Vec<f64>,
+    pub previous: Vec<f64>,
+    pub wave_speed: f64,
+    pub damping: f64,
+}
+
+impl WaveField {
+    /// Creates a field at rest in the given initial displacement.
+    pub fn new(signal: Vec<f64>, wave_speed: f64, damping: f64) -> Self {
+        WaveField {
+            previous: signal.clone(),
+            signal,
+            wave_speed,
+            damping,
+        }
+    }
+
+    fn at(&self, i: isize) -> f64 {
+        if i < 0 {
+            0.0
+        } else {
+            self.signal.get(i as usize).copied().unwrap_or(0.0)
+        }
+    }
+}
+
+impl ResonanceField for WaveField {
+    type Position = usize;
+    type Gradient = f64;
+    type Resonance = f64;
+
+    fn observe(&self, position: &usize) -> f64 {
+        let i = *position as isize;
+        (self.at(i + 1) - self.at(i - 1)) / 2.0
+    }
+
+    fn compute_resonance(&self, position: &usize) -> f64 {
+        self.signal.get(*position).copied().unwrap_or(0.0)
+    }
+
+    /// Injects `influence` at `position`, then advances the field one time step.
+    fn propagate(&mut self, position: &usize, influence: &f64) {
+        if let Some(u) = self.signal.get_mut(*position) {
+            *u += *influence;
+        }
+
+        let c2 = self.wave_speed * self.wave_speed;
+        let next: Vec<f64> = (0..self.signal.len())
+            .map(|i| {
+                let u = self.signal[i];
+                let laplacian = self.at(i as isize + 1) - 2.0 * u + self.at(i as isize - 1);
+                (2.0 * u - (1.0 - self.damping) * self.previous[i] + c2 * laplacian)
+                    / (1.0 + self.damping)
+            })
+            .collect();
+
+        self.previous = std::mem::replace(&mut self.signal, next);
+    }
+
+    fn signal(&self) -> &[f64] {
+        &self.signal
+    }
+
+    fn signal_len(&self) -> usize {
+        self.signal.len()
+    }
+
+    fn domain_label(&self) -> &str {
+        "wave"
+    }
+
+    fn fusion_context(&self) -> FusionContext {
+        FusionContext {
+            domain_entropy: compute_entropy(&self.signal),
+            domain_label: Some("wave".into()),
+            ..FusionContext::default()
+        }
+    }
+}
+
+/// Two fields sharing a position space whose resonances bleed into each other in
+/// proportion to the entanglement between their domains. Domains are looked up from
+/// `domain_label` via `SemanticDomain::from_label`; unrecognised labels are uncoupled.
+pub struct CoupledFields<A, B> {
+    pub a: A,
+    pub b: B,
+    pub entanglement: SimpleEntangleMap,
+}
+
+impl<A, B> CoupledFields<A, B>
+where
+    A: ResonanceField,
+    B: ResonanceField,
+{
+    pub fn new(a: A, b: B, entanglement: SimpleEntangleMap) -> Self {
+        CoupledFields { a, b, entanglement }
+    }
+
+    /// Absolute coupling strength between the two fields' domains, checking both
+    /// directions of the (directed) map; 0.0 if either label has no domain.
+    pub fn coupling_strength(&self) -> f64 {
+        let (Some(da), Some(db)) = (
+            SemanticDomain::from_label(self.a.domain_label()),
+            SemanticDomain::from_label(self.b.domain_label()),
+        ) else {
+            return 0.0;
+        };
+        let forward = self.entanglement.get_coupling(&da, &db).strength().abs();
+        let backward = self.entanglement.get_coupling(&db, &da).strength().abs();
+        forward.max(backward)
+    }
+}
+
+impl<A, B> ResonanceField for CoupledFields<A, B>
+where
+    A: ResonanceField,
+    A::Resonance: ResonanceValue,
+    B: ResonanceField<Position = A::Position, Resonance = A::Resonance>,
+{
+    type Position = A::Position;
+    type Gradient = A::Gradient;
+    type Resonance = A::Resonance;
+
+    fn observe(&self, position: &Self::Position) -> Self::Gradient {
+        self.a.observe(position)
+    }
+
+    /// `a`'s resonance mixed with `b`'s by weight `c / (1 + c)` for coupling strength `c`:
+    /// uncoupled fields report `a` alone, and stronger coupling moves the result toward `b`.
+    fn compute_resonance(&self, position: &Self::Position) -> Self::Resonance {
+        let c = self.coupling_strength();
+        let own = self.a.compute_resonance(position);
+        let other = self.b.compute_resonance(position);
+        own.blend(&other, c / (1.0 + c))
+    }
+
+    fn propagate(&mut self, position: &Self::Position, influence: &Self::Resonance) {
+        self.a.propagate(position, influence);
+        self.b.propagate(position, influence);
+    }
+
+    fn signal(&self) -> &[f64] {
+        self.a.signal()
+    }
+
+    fn signal_len(&self) -> usize {
+        self.a.signal_len()
+    }
+
+    fn domain_label(&self) -> &str {
+        self.a.domain_label()
+    }
+
+    fn fusion_context(&self) -> FusionContext {
+        self.a.fusion_context()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entangle::Coupling;
+    use crate::wavelet::EntropyWeightedFusion;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn push(amplitude: f64) -> Resonance {
+        Resonance { amplitude, frequency: 0.0 }
+    }
+
+    #[test]
+    fn threshold_callback_fires_on_crossings_in_both_directions() {
+        let mut field = GridField::new(vec![vec![0.45, 0.0], vec![0.0, 0.0]]);
+        field.propagation_gain = 1.0;
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let sink = events.clone();
+        let record = move |x, y, c| sink.borrow_mut().push((x, y, c));
+        field.set_threshold_callback(0.5, Box::new(record));
+
+        let origin = Position { x: 0.0, y: 0.0 };
+        field.propagate(&origin, &push(0.1));
+        field.propagate(&origin, &push(0.1));
+        field.propagate(&origin, &push(-0.3));
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        assert_eq!((events[0].0, events[0].1), (0, 0));
+        assert!(events[0].2 >= 0.5);
+        assert!(events[1].2 < 0.5);
+    }
+
+    #[test]
+    fn signal_follows_the_flatten_order() {
+        let mut field = GridField::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+        assert_eq!(field.signal(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        field.set_flatten_order(FlattenOrder::ColumnMajor);
+        assert_eq!(field.signal(), &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+    }
+
+    #[test]
+    fn signal_stays_in_sync_with_edits_and_propagation() {
+        let mut field = GridField::new(vec![vec![0.0; 2]; 2]);
+        assert_eq!(field.signal(), &[0.0; 4]);
+
+        assert!(field.set_cell(1, 0, 0.5));
+        assert!(!field.set_cell(2, 0, 0.5));
+        assert_eq!(field.signal(), &[0.0, 0.5, 0.0, 0.0]);
+
+        field.propagation_gain = 1.0;
+        field.propagate(&Position { x: 0.0, y: 1.0 }, &push(0.25));
+        assert_eq!(field.signal(), &[0.0, 0.5, 0.25, 0.0]);
+
+        field.set_coherence_map(vec![vec![7.0]; 3]);
+        assert_eq!((field.width(), field.height()), (1, 3));
+        assert_eq!(field.signal(), &[7.0; 3]);
+    }
+
+    #[test]
+    fn entropy_source_selects_the_profile_behind_domain_entropy() {
+        let signal = vec![1.0, 1.0, 1.0, 1.0];
+        let resonance = vec![1.0, 0.0, 0.0, 0.0];
+        let curvature = vec![1.0, 1.0, 0.0, 0.0];
+        let field = |source| {
+            BiologicalField::new(signal.clone(), vec![], resonance.clone(), curvature.clone())
+                .with_entropy_source(source)
+        };
+        let entropy = |source| field(source).fusion_context().domain_entropy;
+
+        assert_eq!(field(EntropySource::default()).entropy_source, EntropySource::Signal);
+        assert!((entropy(EntropySource::Signal) - compute_entropy(&signal)).abs() < 1e-12);
+        assert!((entropy(EntropySource::Resonance) - compute_entropy(&resonance)).abs() < 1e-12);
+        assert!((entropy(EntropySource::Curvature) - compute_entropy(&curvature)).abs() < 1e-12);
+        assert!((entropy(EntropySource::Signal) - 2.0).abs() < 1e-12);
+        assert_eq!(entropy(EntropySource::Resonance), 0.0);
+    }
+
+    #[test]
+    fn spectrogram_peak_moves_between_tone_segments() {
+        // Windows of 100 samples exercise the non-power-of-two transform path
+        let tone = |cycles: f64| {
+            (0..400).map(move |i| (2.0 * std::f64::consts::PI * cycles * i as f64 / 100.0).sin())
+        };
+        let signal: Vec<f64> = tone(5.0).chain(tone(20.0)).collect();
+        let frames = spectrogram(&signal, 100, 100);
+        assert_eq!(frames.len(), 8);
+        let peak = |frame: &Vec<f64>| {
+            (0..frame.len()).max_by(|&a, &b| frame[a].total_cmp(&frame[b])).unwrap()
+        };
+        assert!(frames[..4].iter().all(|f| peak(f) == 5));
+        assert!(frames[4..].iter().all(|f| peak(f) == 20));
+    }
+
+    #[test]
+    fn resonance_along_samples_every_position() {
+        let grid = GridField::new(vec![vec![0.0, 1.0, 3.0], vec![0.5, 0.5, 0.5]]);
+        let path = [
+            Position { x: 0.0, y: 0.0 },
+            Position { x: 1.0, y: 1.0 },
+            Position { x: 2.0, y: 0.0 },
+        ];
+        let along = grid.resonance_along(&path);
+        assert_eq!(along.len(), path.len());
+        for (r, p) in along.iter().zip(&path) {
+            let expected = grid.compute_resonance(p);
+            assert_eq!((r.amplitude, r.frequency), (expected.amplitude, expected.frequency));
+        }
+
+        let bio = BiologicalField::new(vec![0.0; 3], vec![], vec![0.2, 0.4, 0.8], vec![]);
+        // Positions past the end of the resonance profile read as silence
+        assert_eq!(bio.resonance_along(&[2, 0, 5]), vec![0.8, 0.2, 0.0]);
+        assert!(bio.resonance_along(&[]).is_empty());
+    }
+
+    #[test]
+    fn amplitude_envelope_reads_amplitudes_from_either_resonance_type() {
+        let grid = GridField::new(vec![vec![0.0, 1.0, 3.0], vec![0.5, 0.5, 0.5]]);
+        let path = [Position { x: 0.0, y: 0.0 }, Position { x: 2.0, y: 1.0 }];
+        let expected: Vec<f64> = path.iter().map(|p| grid.compute_resonance(p).amplitude).collect();
+        assert_eq!(grid.amplitude_envelope(&path), expected);
+
+        let bio = BiologicalField::new(vec![0.0; 3], vec![], vec![0.2, -0.4, 0.8], vec![]);
+        assert_eq!(bio.amplitude_envelope(&[1, 2]), vec![-0.4, 0.8]);
+    }
+
+    /// Strongest amplitude along `positions` with its frequency, for any resonance type.
+    fn strongest<F>(field: &F, positions: &[F::Position]) -> (f64, f64)
+    where
+        F: ResonanceField,
+        F::Resonance: ResonanceValue,
+    {
+        field
+            .resonance_along(positions)
+            .iter()
+            .map(|r| (r.amplitude(), r.frequency()))
+            .fold((f64::NEG_INFINITY, 0.0), |best, r| if r.0 > best.0 { r } else { best })
+    }
+
+    #[test]
+    fn generic_code_reads_both_resonance_representations() {
+        let bio = BiologicalField::new(vec![0.0; 3], vec![], vec![0.2, 0.9, 0.4], vec![]);
+        assert_eq!(strongest(&bio, &[0, 1, 2]), (0.9, 0.0));
+
+        let grid = GridField::new(vec![vec![0.0, 1.0, 3.0], vec![0.5, 0.5, 0.5]]);
+        let path = [Position { x: 0.0, y: 1.0 }, Position { x: 1.0, y: 0.0 }];
+        let best = grid.compute_resonance(&path[1]);
+        assert!(best.amplitude > grid.compute_resonance(&path[0]).amplitude);
+        assert_eq!(strongest(&grid, &path), (best.amplitude, best.frequency));
+        assert!(((0.7).blend(&0.3, 0.25) - 0.6).abs() < 1e-12);
+    }
+
+    #[test]
+    fn propagation_gain_scales_the_applied_delta() {
+        let centre = Position { x: 1.0, y: 1.0 };
+        let spread = |gain: f64, kernel: Option<[[f64; 3]; 3]>| {
+            let mut field = GridField::new(vec![vec![0.0; 3]; 3]);
+            assert_eq!(field.propagation_gain, 0.01);
+            field.propagation_gain = gain;
+            field.propagation_kernel = kernel;
+            field.propagate(&centre, &push(2.0));
+            field.coherence_map().to_vec()
+        };
+
+        // The default touches only the target cell
+        let single = spread(0.01, None);
+        assert!((single[1][1] - 0.02).abs() < 1e-12);
+        assert_eq!(single.iter().flatten().filter(|&&v| v != 0.0).count(), 1);
+        let tripled = spread(0.03, None);
+        assert!((tripled[1][1] - 3.0 * single[1][1]).abs() < 1e-12);
+
+        let cross = [[0.0, 0.5, 0.0], [0.5, 1.0, 0.5], [0.0, 0.5, 0.0]];
+        let spread_out = spread(0.1, Some(cross));
+        assert!((spread_out[1][1] - 0.2).abs() < 1e-12);
+        assert!((spread_out[0][1] - 0.1).abs() < 1e-12);
+        assert_eq!(spread_out[0][0], 0.0);
+    }
+
+    #[test]
+    fn wave_field_spreads_an_impulse_outward() {
+        let mut field = WaveField::new(vec![0.0; 41], 0.8, 0.05);
+        let extent = |field: &WaveField| {
+            let active: Vec<usize> = (0..41).filter(|&i| field.signal[i].abs() > 1e-9).collect();
+            (active[0], active[active.len() - 1])
+        };
+
+        field.propagate(&20, &1.0);
+        let mut previous = extent(&field);
+        for _ in 0..8 {
+            field.propagate(&20, &0.0);
+            let (lo, hi) = extent(&field);
+            assert!(lo < previous.0 && hi > previous.1, "{previous:?} -> {:?}", (lo, hi));
+            // Symmetric about the impulse
+            assert_eq!(20 - lo, hi - 20);
+            previous = (lo, hi);
+        }
+        assert_eq!(field.compute_resonance(&0), 0.0);
+    }
+
+    /// Scores Haar as NaN and every other basis by a fixed rank, so ties are easy to build.
+    struct FixedScores;
+
+    impl WaveletFusionStrategy for FixedScores {
+        fn fuse(
+            decompositions: &[WaveletDecomposition],
+            context: &FusionContext,
+        ) -> WaveletDecomposition {
+            EntropyWeightedFusion::fuse(decompositions, context)
+        }
+
+        fn score_basis(basis: &WaveletBasis, _signal: &[f64], _context: &FusionContext) -> f64 {
+            match basis {
+                WaveletBasis::Haar => f64::NAN,
+                WaveletBasis::Biorthogonal(..) => 0.5,
+                _ => 1.0,
+            }
+        }
+    }
+
+    #[test]
+    fn dominant_basis_skips_nan_scores_and_breaks_ties_by_order() {
+        let field = BiologicalField::new(vec![0.1, 0.4, 0.2, 0.9], vec![], vec![], vec![]);
+        let engine = |bases: Vec<WaveletBasis>| WaveletEngine::new(bases, FixedScores);
+
+        let tied = engine(vec![
+            WaveletBasis::Haar,
+            WaveletBasis::Biorthogonal(2, 2),
+            WaveletBasis::Daubechies(4),
+            WaveletBasis::Custom("identity".into()),
+        ]);
+        assert_eq!(field.dominant_basis(&tied), Some(WaveletBasis::Daubechies(4)));
+        assert_eq!(field.dominant_basis(&engine(vec![WaveletBasis::Haar])), None);
+        assert_eq!(field.dominant_basis(&engine(vec![])), None);
+    }
+
+    #[test]
+    fn local_correlation_follows_matching_and_opposing_segments() {
+        let a = [1.0, 3.0, 2.0, 5.0, 1.0, 3.0, 2.0, 5.0];
+        // b copies a over the first half and mirrors it over the second
+        let b = [1.0, 3.0, 2.0, 5.0, -1.0, -3.0, -2.0, -5.0];
+        let correlation = local_correlation(&a, &b, 4);
+
+        assert_eq!(correlation.len(), 5);
+        assert!((correlation[0] - 1.0).abs() < 1e-12);
+        assert!((correlation[4] + 1.0).abs() < 1e-12);
+        assert_eq!(local_correlation(&a, &[2.0; 8], 4), vec![0.0; 5]);
+        assert!(local_correlation(&a, &b, 9).is_empty());
+    }
+
+    #[test]
+    fn try_observe_rejects_positions_outside_the_grid() {
+        let field = GridField::new(vec![vec![0.0, 1.0, 3.0], vec![0.5, 0.5, 0.5]]);
+        let inside = field.try_observe(&Position { x: 2.0, y: 1.0 }).unwrap();
+        assert_eq!(inside.direction, [0.0, 2.5]);
+
+        for (x, y) in [(3.0, 0.0), (0.0, 2.0), (-0.5, 0.0), (f64::NAN, 0.0)] {
+            let err = field.try_observe(&Position { x, y }).unwrap_err();
+            assert!(matches!(err, CurvatureError::Field(FieldError::OutOfBounds { .. })));
+        }
+        // The clamping variant keeps working at the same positions
+        let clamped = field.observe(&Position { x: 3.0, y: 0.0 });
+        let edge = field.try_observe(&Position { x: 2.0, y: 0.0 }).unwrap();
+        assert_eq!(clamped.magnitude, edge.magnitude);
+    }
+
+    #[test]
+    fn gated_spectrum_skips_quiet_fields() {
+        let engine = WaveletEngine::new(vec![WaveletBasis::Haar], EntropyWeightedFusion);
+        let quiet = BiologicalField::new(vec![0.01, -0.02, 0.01, 0.0], vec![], vec![], vec![]);
+        let active = BiologicalField::new(vec![0.2, -1.5, 0.8, 0.1], vec![], vec![], vec![]);
+
+        assert!(quiet.fused_spectrum_gated(&engine, 1, 0.5).is_none());
+        let fused = active.fused_spectrum_gated(&engine, 1, 0.5).expect("active field fuses");
+        assert_eq!(fused.coefficients, active.fused_spectrum(&engine, 1).coefficients);
+    }
+
+
+    struct Constant {
+        label: &'static str,
+        value: f64,
+        signal: Vec<f64>,
+    }
+
+    impl ResonanceField for Constant {
+        type Position = usize;
+        type Gradient = f64;
+        type Resonance = f64;
+
+        fn observe(&self, _position: &usize) -> f64 {
+            0.0
+        }
+
+        fn compute_resonance(&self, _position: &usize) -> f64 {
+            self.value
+        }
+
+        fn propagate(&mut self, _position: &usize, _influence: &f64) {}
+
+        fn signal(&self) -> &[f64] {
+            &self.signal
+        }
+
+        fn domain_label(&self) -> &str {
+            self.label
+        }
+
+        fn fusion_context(&self) -> FusionContext {
+            FusionContext::default()
+        }
+    }
+
+    fn coupled(strength: f64) -> CoupledFields<Constant, Constant> {
+        let mut map = SimpleEntangleMap::new();
+        let (quantum, linguistic) = (SemanticDomain::Quantum, SemanticDomain::Linguistic);
+        map.update_coupling(&quantum, &linguistic, Coupling::new(strength, 0.0));
+        let a = Constant { label: "quantum", value: 0.0, signal: vec![0.0] };
+        let b = Constant { label: "linguistic", value: 1.0, signal: vec![1.0] };
+        CoupledFields::new(a, b, map)
+    }
+
+    #[test]
+    fn stronger_coupling_pulls_resonance_toward_the_other_field() {
+        let blended: Vec<f64> =
+            [0.0, 0.5, 2.0, 10.0].iter().map(|&c| coupled(c).compute_resonance(&0)).collect();
+        assert_eq!(blended[0], 0.0);
+        assert!(blended.windows(2).all(|w| w[0] < w[1]));
+        assert!((blended[2] - 2.0 / 3.0).abs() < 1e-12);
+        assert!(blended[3] < 1.0);
+    }
+
+    #[test]
+    fn coupling_is_read_in_either_direction_and_ignores_unknown_labels() {
+        let mut fields = coupled(0.0);
+        let (linguistic, quantum) = (SemanticDomain::Linguistic, SemanticDomain::Quantum);
+        fields.entanglement.update_coupling(&linguistic, &quantum, Coupling::new(-0.4, 0.0));
+        assert!((fields.coupling_strength() - 0.4).abs() < 1e-12);
+        fields.b.label = "GridField";
+        assert_eq!(fields.coupling_strength(), 0.0);
+    }
+
+
+    fn assert_signal_len<F: ResonanceField>(field: &F, expected: usize) {
+        assert_eq!(field.signal_len(), field.signal().len());
+        assert_eq!(field.signal_len(), expected);
+    }
+
+    #[test]
+    fn signal_len_matches_the_materialised_signal_for_each_field() {
+        let mut grid = GridField::new(vec![vec![0.0; 3]; 2]);
+        assert_signal_len(&grid, 6);
+        grid.set_flatten_order(FlattenOrder::ColumnMajor);
+        assert_signal_len(&grid, 6);
+
+        let tags = vec!["a".to_string(); 4];
+        let biological = BiologicalField::new(vec![0.1; 4], tags, vec![0.5; 4], vec![0.0; 4]);
+        assert_signal_len(&biological, 4);
+        assert_signal_len(&WaveField::new(vec![0.0; 7], 0.5, 0.1), 7);
+        assert_signal_len(&coupled(1.0), 1);
+        assert_signal_len(&GridField::new(Vec::new()), 0);
+    }
+
+    #[test]
+    fn grid_signal_len_counts_ragged_rows_without_flattening() {
+        let mut grid = GridField::new(vec![vec![0.0; 3], vec![0.0; 1], vec![0.0; 5]]);
+        assert_eq!(grid.signal_len(), 7);
+        assert!(grid.flat.get().is_none());
+        assert_signal_len(&grid, 7);
+        grid.set_flatten_order(FlattenOrder::ColumnMajor);
+        assert_signal_len(&grid, 7);
+    }
+
+
+    #[test]
+    fn dominant_frequency_follows_an_oscillating_grid_pattern() {
+        // Slopes oscillate with a period of 8 cells, so the gradient magnitude does too
+        let slope = |x: usize| 1.0 + 0.5 * (std::f64::consts::TAU * x as f64 / 8.0).sin();
+        let row: Vec<f64> = (0..65)
+            .scan(0.0, |level, x| {
+                *level += slope(x);
+                Some(*level)
+            })
+            .collect();
+        let field = GridField::new(vec![row]);
+        let path: Vec<Position> = (1..65).map(|x| Position { x: x as f64, y: 0.0 }).collect();
+        assert!((field.dominant_frequency(&path) - 0.125).abs() < 1e-12);
+
+        assert_eq!(field.dominant_frequency(&path[..1]), 0.0);
+        let flat = GridField::new(vec![(0..65).map(|x| x as f64).collect()]);
+        assert_eq!(flat.dominant_frequency(&path), 0.0);
+    }
+
+    #[test]
+    fn dominant_frequency_reads_scalar_resonance_directly() {
+        let resonance: Vec<f64> =
+            (0..40).map(|i| (std::f64::consts::TAU * 0.25 * i as f64).cos()).collect();
+        let field = BiologicalField::new(vec![0.0; 40], vec![], resonance, vec![0.0; 40]);
+        let path: Vec<usize> = (0..40).collect();
+        assert!((field.dominant_frequency(&path) - 0.25).abs() < 1e-12);
+    }
+
+
+    #[test]
+    fn grids_converge_only_when_every_cell_is_within_tolerance() {
+        let cells = vec![vec![0.2, 0.4], vec![0.6, 0.8]];
+        let grid = GridField::new(cells.clone());
+        assert!(grid.has_converged(&GridField::new(cells.clone()), 0.0));
+
+        let mut moved = GridField::new(cells);
+        assert!(moved.set_cell(1, 1, 0.85));
+        assert!(!grid.has_converged(&moved, 0.01));
+        assert!(grid.has_converged(&moved, 0.06));
+        assert!(moved.has_converged(&grid, 0.06));
+
+        assert!(!grid.has_converged(&GridField::new(vec![vec![0.2, 0.4]]), 1.0));
+        assert!(!grid.has_converged(&GridField::new(vec![vec![0.2], vec![0.6, 0.8]]), 1.0));
+        let with_nan = GridField::new(vec![vec![0.2, f64::NAN], vec![0.6, 0.8]]);
+        assert!(!grid.has_converged(&with_nan, 1.0));
+    }
+
+
+    #[test]
+    fn biological_field_literal_defaults_the_entropy_source() {
+        let field = BiologicalField {
+            signal: vec![1.0, 0.0],
+            tags: vec![],
+            resonance: vec![],
+            curvature: vec![],
+            ..Default::default()
+        };
+        assert_eq!(field.entropy_source, EntropySource::Signal);
+        let expected = compute_entropy(&[1.0, 0.0]);
+        assert!((field.fusion_context().domain_entropy - expected).abs() < 1e-12);
+    }
+
+
+    #[test]
+    fn propagate_ignores_cells_outside_a_ragged_grid() {
+        let kernel = [[0.0, 1.0, 0.0], [1.0, 1.0, 1.0], [0.0, 1.0, 0.0]];
+        for kernel in [None, Some(kernel)] {
+            let mut field = GridField::new(vec![vec![0.0; 3], vec![0.0; 1]]);
+            field.propagation_gain = 1.0;
+            field.propagation_kernel = kernel;
+            // (2, 1) lies past the short second row; (5, 5) and (inf, 0) are off the grid
+            for (x, y) in [(2.0, 1.0), (5.0, 5.0), (f64::INFINITY, 0.0)] {
+                field.propagate(&Position { x, y }, &push(1.0));
+            }
+            let touched = field.coherence_map().iter().flatten().filter(|&&v| v != 0.0).count();
+            // Only the kernel reaches back onto the grid, at (2, 0) from (2, 1)
+            assert_eq!(touched, usize::from(kernel.is_some()));
+        }
+    }
+
+
+    #[test]
+    fn try_observe_looks_cells_up_on_a_ragged_grid() {
+        let field = GridField::new(vec![vec![0.0, 1.0, 3.0], vec![0.5]]);
+        // (2, 1) is inside the first row's width but past the end of the second row
+        let err = field.try_observe(&Position { x: 2.0, y: 1.0 }).unwrap_err();
+        assert_eq!(err, CurvatureError::Field(FieldError::OutOfBounds { x: 2.0, y: 1.0 }));
+        assert_eq!(field.try_observe(&Position { x: 0.0, y: 1.0 }).unwrap().direction, [0.0, -0.5]);
+        // Clamping lands on the short row's last cell instead of panicking
+        assert_eq!(field.observe(&Position { x: 2.0, y: 1.0 }).direction, [0.0, -0.5]);
+    }
+
+
+    #[test]
+    fn grid_dimensions_come_from_the_map() {
+        let field = GridField::new(vec![vec![0.0; 4]; 2]);
+        assert_eq!((field.width(), field.height()), (4, 2));
+        let empty = GridField::new(vec![]);
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+    }
+}
@@ -2,13 +2,160 @@
 /// resonance fields, entanglement maps, and law synthesis.
 /// Defines the SemanticEngine struct and related visualizations.
 use coheron::beliefs::{GaussianBelief, Observation};
-use crate::coherence::CoherencePulse;
+use crate::coherence::{CoherencePulse, EntropyReducer, Recohere};
 use crate::entangle::{SemanticDomain, SimpleEntangleMap};
-use coheron::fusion::{BeliefFusion, FusionStrategy};
+use coheron::fusion::BeliefFusion;
 use crate::resonance::{Resonance, EntangleMap, LawSynthEngine, Position, ResonanceField};
 use coheron::structs::{ControlLaw};
 use coheron::traits::{BeliefTensor};
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+use std::cell::{Cell, RefCell};
+use std::ops::ControlFlow;
+
+thread_local! {
+    // Shared noise source for the example beliefs and fields, so runs can be re-seeded.
+    static RNG: RefCell<StdRng> = RefCell::new(StdRng::from_rng(&mut rand::rng()));
+}
+
+/// Re-seeds the noise source used by `SimpleBelief` and a default `Field` on this thread.
+pub fn reseed(seed: u64) {
+    RNG.with(|rng| *rng.borrow_mut() = StdRng::seed_from_u64(seed));
+}
+
+fn noise() -> f64 {
+    RNG.with(|rng| rng.borrow_mut().random::<f64>())
+}
+
+/// `RngCore` view of this thread's shared noise source (the one `reseed` re-seeds).
+/// `Field` draws from it unless built with `Field::with_rng`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ThreadNoise;
+
+impl RngCore for ThreadNoise {
+    fn next_u32(&mut self) -> u32 {
+        RNG.with(|rng| rng.borrow_mut().next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        RNG.with(|rng| rng.borrow_mut().next_u64())
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        RNG.with(|rng| rng.borrow_mut().fill_bytes(dst))
+    }
+}
+
+/// Seeding a `ThreadNoise` re-seeds the thread's shared source, as `reseed` does.
+impl SeedableRng for ThreadNoise {
+    type Seed = <StdRng as SeedableRng>::Seed;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        RNG.with(|rng| *rng.borrow_mut() = StdRng::from_seed(seed));
+        ThreadNoise
+    }
+
+    fn seed_from_u64(state: u64) -> Self {
+        reseed(state);
+        ThreadNoise
+    }
+}
+
+/// Summary of a single engine step.
+#[derive(Debug, Clone)]
+pub struct StepReport {
+    pub step: usize,
+    pub position: Position,
+    pub fused_mean: f64,
+    pub resonance: Resonance,
+    /// Finite-difference sensitivities of the applied control, ordered as
+    /// `[dtorque/dmean, dtorque/damplitude, dalignment/dmean, dalignment/damplitude]`.
+    /// Only filled by `SemanticEngine::step_with_sensitivity`.
+    pub sensitivity: Option<[f64; 4]>,
+}
+
+/// Beliefs whose mean can be nudged, used to probe control sensitivity.
+pub trait MeanPerturb {
+    fn perturb_mean(&self, delta: f64) -> Self;
+}
+
+impl MeanPerturb for GaussianBelief {
+    fn perturb_mean(&self, delta: f64) -> Self {
+        GaussianBelief {
+            mean: self.mean + delta,
+            ..self.clone()
+        }
+    }
+}
+
+/// Variance floor used when recohering a `GaussianBelief`, which carries no floor of its own.
+const GAUSSIAN_VARIANCE_FLOOR: f64 = 1e-3;
+
+impl Recohere for GaussianBelief {
+    fn recohere(&mut self, factor: f64) {
+        if self.variance > GAUSSIAN_VARIANCE_FLOOR {
+            self.variance = GAUSSIAN_VARIANCE_FLOOR
+                + (1.0 - factor) * (self.variance - GAUSSIAN_VARIANCE_FLOOR);
+        }
+    }
+}
+
+/// Motion model turning a synthesized `ControlLaw` into the next position.
+pub trait ControlApplicator {
+    fn apply(&self, position: Position, law: &ControlLaw, dt: f64) -> Position;
+
+    /// Clears any internal motion state (such as heading); called by `SemanticEngine::reset`.
+    fn reset(&self) {}
+}
+
+/// Unicycle model: `torque` turns the heading, `alignment` is the forward speed.
+/// The heading starts at 0 (facing +x) and is carried between steps.
+#[derive(Debug, Clone, Default)]
+pub struct UnicycleApplicator {
+    pub heading: Cell<f64>,
+}
+
+impl ControlApplicator for UnicycleApplicator {
+    fn apply(&self, position: Position, law: &ControlLaw, dt: f64) -> Position {
+        let heading = self.heading.get() + law.torque * dt;
+        self.heading.set(heading);
+        Position {
+            x: position.x + law.alignment * heading.cos() * dt,
+            y: position.y + law.alignment * heading.sin() * dt,
+        }
+    }
+
+    fn reset(&self) {
+        self.heading.set(0.0);
+    }
+}
+
+/// Holonomic model: `alignment` and `torque` are velocities along x and y.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HolonomicApplicator;
+
+impl ControlApplicator for HolonomicApplicator {
+    fn apply(&self, position: Position, law: &ControlLaw, dt: f64) -> Position {
+        Position {
+            x: position.x + law.alignment * dt,
+            y: position.y + law.torque * dt,
+        }
+    }
+}
 
+/// Callback invoked with each `StepReport` during `SemanticEngine::run`.
+pub type StepCallback = Box<dyn FnMut(&StepReport) -> ControlFlow<()>>;
+
+/// Beliefs, field and entanglement as they were when the engine was built.
+struct InitialState<B, F, E> {
+    beliefs: Vec<B>,
+    field: F,
+    entanglement: E,
+}
+
+/// Engines are built with `SemanticEngine::new`, which snapshots the initial state that
+/// `reset` restores; the snapshot is private, so struct literals no longer compile outside
+/// this crate (a breaking change).
 pub struct SemanticEngine<B, F, E, S, BF>
 where
     B: BeliefTensor,
@@ -19,14 +166,84 @@ where
     F::Position: Copy,
 {
     pub beliefs: Vec<B>,
-    pub fusion_strategy: Box<dyn FusionStrategy<B>>,
     pub field: F,
     pub entanglement: E,
     pub synthesizer: S,
+    /// Fuses the beliefs into the composite posterior each step (via `BF::fuse`).
     pub belief_fusion: BF,
     pub position: F::Position,
     pub pulse: Box<dyn CoherencePulse<B, E>>,
+    /// Combines every belief's entropy when deciding whether `pulse` fires.
+    pub entropy_reducer: EntropyReducer,
     pub step: usize, // Add step counter
+    /// Moves `position` according to each synthesized control law.
+    pub control: Box<dyn ControlApplicator>,
+    /// Time step passed to `control`.
+    pub dt: f64,
+    /// Reports of every step taken since construction or the last `reset`.
+    pub history: Vec<StepReport>,
+    /// Invoked after each step of `run`; returning `ControlFlow::Break` stops the run.
+    pub on_step: Option<StepCallback>,
+    initial: InitialState<B, F, E>,
+}
+
+impl<B, F, E, S, BF> SemanticEngine<B, F, E, S, BF>
+where
+    B: BeliefTensor + Clone,
+    F: ResonanceField<Position = Position, Resonance = Resonance> + Clone,
+    E: EntangleMap + Clone,
+    S: LawSynthEngine<B, F, E, ControlLaw = ControlLaw>,
+    BF: BeliefFusion<B>,
+{
+    /// Creates an engine at step 0 at the origin, reducing entropy with
+    /// `EntropyReducer::Max` and moving with a `UnicycleApplicator` at `dt = 1.0`.
+    /// The beliefs, field and entanglement are snapshotted for `reset`.
+    pub fn new(
+        beliefs: Vec<B>,
+        field: F,
+        entanglement: E,
+        synthesizer: S,
+        belief_fusion: BF,
+        pulse: Box<dyn CoherencePulse<B, E>>,
+    ) -> Self {
+        let initial = InitialState {
+            beliefs: beliefs.clone(),
+            field: field.clone(),
+            entanglement: entanglement.clone(),
+        };
+        SemanticEngine {
+            beliefs,
+            field,
+            entanglement,
+            synthesizer,
+            belief_fusion,
+            position: Position { x: 0.0, y: 0.0 },
+            pulse,
+            entropy_reducer: EntropyReducer::default(),
+            step: 0,
+            control: Box::new(UnicycleApplicator::default()),
+            dt: 1.0,
+            history: Vec::new(),
+            on_step: None,
+            initial,
+        }
+    }
+
+    /// Returns the engine to step 0 at the origin with an empty history, restores the
+    /// beliefs, field and entanglement captured by `new`, resets the control applicator
+    /// and re-seeds both the shared noise source and the field's, so repeated trials with
+    /// the same seed reproduce each other without a rebuilt engine.
+    pub fn reset(&mut self, seed: u64) {
+        self.beliefs = self.initial.beliefs.clone();
+        self.field = self.initial.field.clone();
+        self.entanglement = self.initial.entanglement.clone();
+        self.step = 0;
+        self.history.clear();
+        self.position = Position { x: 0.0, y: 0.0 };
+        self.control.reset();
+        reseed(seed);
+        self.field.reseed(seed);
+    }
 }
 
 impl<B, F, E, S, BF> SemanticEngine<B, F, E, S, BF>
@@ -35,10 +252,17 @@ where
     B::Posterior: BeliefTensor, // Ensure Posterior also implements BeliefTensor
     F: ResonanceField<Position = Position, Resonance = Resonance>,
     E: EntangleMap,
-    S: LawSynthEngine<B, F, E>,
+    S: LawSynthEngine<B, F, E, ControlLaw = ControlLaw>,
     BF: BeliefFusion<B>,
 {
-    pub fn step(&mut self) {
+    pub fn step(&mut self) -> StepReport {
+        let report = self.advance().0;
+        self.history.push(report.clone());
+        report
+    }
+
+    /// Performs one step, returning the report and the fused posterior it used.
+    fn advance(&mut self) -> (StepReport, B::Posterior) {
         // Update each belief individually
         for belief in &mut self.beliefs {
             let obs = belief.observe();
@@ -46,7 +270,7 @@ where
         }
 
         // Fuse beliefs into a composite posterior
-        let fused = self.fusion_strategy.fuse(&self.beliefs);
+        let fused = BF::fuse(&self.beliefs);
 
         // Compute resonance and synthesize control
         let resonance = self.field.compute_resonance(&self.position);
@@ -58,12 +282,11 @@ where
         self.position = self.apply_control(&law);
         self.field.propagate(&self.position, &resonance);
 
-        if let Some(belief) = self.beliefs.first()
-            && self.pulse.should_trigger(belief) {
-                for belief in &mut self.beliefs {
-                    self.pulse.trigger(belief, &mut self.entanglement);
-                }
+        if self.should_pulse(&resonance) {
+            for belief in &mut self.beliefs {
+                self.pulse.trigger(belief, &mut self.entanglement);
             }
+        }
 
         println!(
             "Step {:>2}: Pos ({:.2}, {:.2}), Fused Mean {:.2}, Resonance Amp {:.2}, Freq {:.2}",
@@ -74,12 +297,99 @@ where
             resonance.amplitude,
             resonance.frequency
         );
+
+        let report = StepReport {
+            step: self.step,
+            position: self.position,
+            fused_mean: fused.mean(),
+            resonance,
+            sensitivity: None,
+        };
         self.step += 1; // Increment step counter
+        (report, fused)
+    }
+
+    /// Runs up to `steps` steps, stopping early if the `on_step` callback breaks.
+    pub fn run(&mut self, steps: usize) -> Vec<StepReport> {
+        let mut reports = Vec::with_capacity(steps);
+        for _ in 0..steps {
+            let report = self.step();
+            let flow = match self.on_step.as_mut() {
+                Some(callback) => callback(&report),
+                None => ControlFlow::Continue(()),
+            };
+            reports.push(report);
+            if flow.is_break() {
+                break;
+            }
+        }
+        reports
+    }
+
+    /// Asks the pulse whether to fire, combining the beliefs with `entropy_reducer`.
+    fn should_pulse(&self, resonance: &Resonance) -> bool {
+        match self.entropy_reducer.reduce(self.beliefs.iter().map(|b| b.entropy())) {
+            Some(entropy) => self.pulse.should_trigger_aggregate(&self.beliefs, entropy, resonance),
+            None => self
+                .beliefs
+                .iter()
+                .any(|b| self.pulse.should_trigger_with_context(b, resonance)),
+        }
+    }
+
+    fn apply_control(&self, law: &ControlLaw) -> Position {
+        self.control.apply(self.position, law, self.dt)
+    }
+}
+
+impl<B, F, E, S, BF> SemanticEngine<B, F, E, S, BF>
+where
+    B: BeliefTensor,
+    B::Posterior: BeliefTensor + MeanPerturb,
+    F: ResonanceField<Position = Position, Resonance = Resonance>,
+    E: EntangleMap,
+    S: LawSynthEngine<B, F, E, ControlLaw = ControlLaw>,
+    BF: BeliefFusion<B>,
+{
+    /// Like `step`, but also reports how sensitive the synthesized control is to the
+    /// fused belief mean and resonance amplitude, via central finite differences.
+    pub fn step_with_sensitivity(&mut self) -> StepReport {
+        const H: f64 = 1e-6;
+        let (mut report, fused) = self.advance();
+        let resonance = &report.resonance;
+
+        let law_at = |mean_delta: f64, amp_delta: f64| {
+            let belief = fused.perturb_mean(mean_delta);
+            let resonance = Resonance {
+                amplitude: resonance.amplitude + amp_delta,
+                ..resonance.clone()
+            };
+            self.synthesizer
+                .synthesize(&belief, &resonance, &self.entanglement)
+        };
+
+        let (mean_hi, mean_lo) = (law_at(H, 0.0), law_at(-H, 0.0));
+        let (amp_hi, amp_lo) = (law_at(0.0, H), law_at(0.0, -H));
+        report.sensitivity = Some([
+            (mean_hi.torque - mean_lo.torque) / (2.0 * H),
+            (amp_hi.torque - amp_lo.torque) / (2.0 * H),
+            (mean_hi.alignment - mean_lo.alignment) / (2.0 * H),
+            (amp_hi.alignment - amp_lo.alignment) / (2.0 * H),
+        ]);
+        self.history.push(report.clone());
+        report
     }
+}
 
-    fn apply_control(&self, _law: &S::ControlLaw) -> F::Position {
-        self.position // placeholder
+/// Population variance of the beliefs' means: zero when the ensemble agrees,
+/// growing as observations pull beliefs apart.
+pub fn belief_disagreement<B: BeliefTensor>(beliefs: &[B]) -> f64 {
+    if beliefs.is_empty() {
+        return 0.0;
     }
+    let n = beliefs.len() as f64;
+    let mean = beliefs.iter().map(|b| b.mean()).sum::<f64>() / n;
+    beliefs.iter().map(|b| (b.mean() - mean).powi(2)).sum::<f64>() / n
 }
 
 pub struct VisualNode {
@@ -124,6 +434,50 @@ pub struct SemanticState {
 pub struct SimpleBelief {
     pub mean: f64,
     pub variance: f64,
+    /// Lower bound on variance, so repeated updates cannot collapse the belief
+    /// into overconfidence that ignores new observations.
+    pub variance_floor: f64,
+}
+
+impl SimpleBelief {
+    /// Creates a belief with a variance floor of 1e-3.
+    pub fn new(mean: f64, variance: f64) -> Self {
+        SimpleBelief {
+            mean,
+            variance,
+            variance_floor: 1e-3,
+        }
+    }
+
+    pub fn with_variance_floor(mut self, variance_floor: f64) -> Self {
+        self.variance_floor = variance_floor;
+        self
+    }
+}
+
+/// A unit-variance belief centred on zero.
+impl Default for SimpleBelief {
+    fn default() -> Self {
+        SimpleBelief::new(0.0, 1.0)
+    }
+}
+
+impl MeanPerturb for SimpleBelief {
+    fn perturb_mean(&self, delta: f64) -> Self {
+        SimpleBelief {
+            mean: self.mean + delta,
+            ..self.clone()
+        }
+    }
+}
+
+impl Recohere for SimpleBelief {
+    fn recohere(&mut self, factor: f64) {
+        if self.variance > self.variance_floor {
+            self.variance =
+                self.variance_floor + (1.0 - factor) * (self.variance - self.variance_floor);
+        }
+    }
 }
 
 impl BeliefTensor for SimpleBelief {
@@ -133,7 +487,7 @@ impl BeliefTensor for SimpleBelief {
 
     fn observe(&self) -> Self::Observation {
         Observation {
-            signal: self.mean + 0.1 * rand::random::<f64>(), // noisy observation
+            signal: self.mean + 0.1 * noise(), // noisy observation
             noise: 0.1,
         }
     }
@@ -145,7 +499,7 @@ impl BeliefTensor for SimpleBelief {
     fn update(&mut self, obs: &Self::Observation) {
         let weighted = (self.mean + obs.signal) / 2.0;
         self.mean = weighted;
-        self.variance *= 0.9; // gain confidence
+        self.variance = (self.variance * 0.9).max(self.variance_floor); // gain confidence
     }
 
     fn entropy(&self) -> f64 {
@@ -157,15 +511,43 @@ impl BeliefTensor for SimpleBelief {
     }
 }
 
-pub struct Field;
+/// Example field whose observations carry uniform noise drawn from `R`.
+#[derive(Clone)]
+pub struct Field<R: RngCore + SeedableRng = ThreadNoise> {
+    rng: RefCell<R>,
+}
+
+impl Field {
+    /// Creates a field drawing noise from the thread's shared source (see `reseed`).
+    pub fn new() -> Self {
+        Field::with_rng(ThreadNoise)
+    }
+}
+
+impl Default for Field {
+    fn default() -> Self {
+        Field::new()
+    }
+}
+
+impl<R: RngCore + SeedableRng> Field<R> {
+    /// Creates a field drawing noise from `rng`, e.g. a seeded generator. `reset` on an
+    /// engine re-seeds it through `SeedableRng`.
+    pub fn with_rng(rng: R) -> Self {
+        Field {
+            rng: RefCell::new(rng),
+        }
+    }
+}
 
-impl ResonanceField for Field {
+impl<R: RngCore + SeedableRng> ResonanceField for Field<R> {
     type Position = Position;
     type Gradient = f64;
     type Resonance = Resonance;
 
     fn observe(&self, position: &Self::Position) -> f64 {
-        position.x.sin() + position.y.cos() + 0.1 * rand::random::<f64>() // noisy semantic signal
+        let noise = self.rng.borrow_mut().random::<f64>();
+        position.x.sin() + position.y.cos() + 0.1 * noise // noisy semantic signal
     }
 
     fn compute_resonance(&self, position: &Self::Position) -> Resonance {
@@ -179,6 +561,10 @@ impl ResonanceField for Field {
         // Placeholder: could update field state
     }
 
+    fn reseed(&mut self, seed: u64) {
+        *self.rng.get_mut() = R::seed_from_u64(seed);
+    }
+
     fn signal(&self) -> &[f64] {
         // Dummy implementation: return a static slice
         static SIGNAL: [f64; 2] = [0.0, 0.0];
@@ -196,7 +582,7 @@ impl ResonanceField for Field {
 
 pub struct Synth;
 
-impl LawSynthEngine<SimpleBelief, Field, SimpleEntangleMap> for Synth {
+impl<R: RngCore + SeedableRng> LawSynthEngine<SimpleBelief, Field<R>, SimpleEntangleMap> for Synth {
     type ControlLaw = ControlLaw;
 
     fn synthesize(
@@ -213,7 +599,7 @@ impl LawSynthEngine<SimpleBelief, Field, SimpleEntangleMap> for Synth {
 }
 
 // Implement a minimal GaussianBelief for demonstration
-impl LawSynthEngine<GaussianBelief, Field, ()> for Synth {
+impl<R: RngCore + SeedableRng> LawSynthEngine<GaussianBelief, Field<R>, ()> for Synth {
     type ControlLaw = ControlLaw;
 
     fn synthesize(
@@ -252,3 +638,294 @@ impl EntangleMap for () {
         // minimal implementation: do nothing
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coherence::EntropyPulse;
+    use std::rc::Rc;
+
+    struct MeanFusion;
+
+    impl BeliefFusion<SimpleBelief> for MeanFusion {
+        fn fuse(beliefs: &[SimpleBelief]) -> SimpleBelief {
+            let mean = beliefs.iter().map(|b| b.mean).sum::<f64>() / beliefs.len() as f64;
+            SimpleBelief::new(mean, 1.0)
+        }
+    }
+
+    type Engine<R> = SemanticEngine<SimpleBelief, Field<R>, SimpleEntangleMap, Synth, MeanFusion>;
+
+    fn engine<R: RngCore + SeedableRng + Clone>(field: Field<R>) -> Engine<R> {
+        SemanticEngine::new(
+            vec![SimpleBelief::new(0.2, 1.0), SimpleBelief::new(0.6, 2.0)],
+            field,
+            SimpleEntangleMap::new(),
+            Synth,
+            MeanFusion,
+            Box::new(EntropyPulse::new(0.5)),
+        )
+    }
+
+    fn trace(reports: &[StepReport]) -> Vec<(f64, f64, f64)> {
+        reports
+            .iter()
+            .map(|r| (r.position.x, r.position.y, r.fused_mean))
+            .collect()
+    }
+
+    /// Counts its triggers; fires when any aggregated entropy exceeds 1.0.
+    struct CountingPulse(Rc<Cell<usize>>);
+
+    impl CoherencePulse<SimpleBelief, SimpleEntangleMap> for CountingPulse {
+        fn trigger(&mut self, _belief: &mut SimpleBelief, _entanglement: &mut SimpleEntangleMap) {
+            self.0.set(self.0.get() + 1);
+        }
+
+        fn should_trigger(&self, belief: &SimpleBelief) -> bool {
+            belief.entropy() > 1.0
+        }
+
+        fn entropy_threshold(&self) -> Option<f64> {
+            Some(1.0)
+        }
+    }
+
+    #[test]
+    fn reducer_fires_on_a_high_entropy_belief_after_the_first() {
+        let triggers = Rc::new(Cell::new(0));
+        let mut engine: Engine<ThreadNoise> = SemanticEngine::new(
+            vec![SimpleBelief::new(0.2, 0.5), SimpleBelief::new(0.6, 50.0)],
+            Field::new(),
+            SimpleEntangleMap::new(),
+            Synth,
+            MeanFusion,
+            Box::new(CountingPulse(triggers.clone())),
+        );
+        engine.step();
+        // Once it fires, the pulse is triggered on every belief
+        assert_eq!(triggers.get(), 2);
+
+        // Max would fire on the second belief; the mean stays below the threshold
+        triggers.set(0);
+        engine.entropy_reducer = EntropyReducer::Mean;
+        engine.beliefs[0].variance = 0.1;
+        engine.beliefs[1].variance = 5.0;
+        engine.step();
+        assert_eq!(triggers.get(), 0);
+    }
+
+    #[test]
+    fn variance_settles_on_the_floor_instead_of_zero() {
+        reseed(3);
+        let mut belief = SimpleBelief::new(0.5, 1.0).with_variance_floor(0.05);
+        for _ in 0..500 {
+            let obs = belief.observe();
+            belief.update(&obs);
+        }
+        assert_eq!(belief.variance, 0.05);
+        assert!(belief.entropy().is_finite());
+    }
+
+    #[test]
+    fn reset_reproduces_a_run() {
+        let mut engine = engine(Field::new());
+        engine.reset(42);
+        let first = trace(&engine.run(6));
+        engine.reset(42);
+        let second = trace(&engine.run(6));
+        assert_eq!(first, second);
+        assert_eq!(engine.history.len(), 6);
+    }
+
+    #[test]
+    fn reset_restores_initial_beliefs() {
+        let mut engine = engine(Field::new());
+        engine.run(4);
+        assert_ne!(engine.beliefs[0].mean, 0.2);
+        engine.reset(7);
+        assert_eq!(engine.step, 0);
+        assert!(engine.history.is_empty());
+        assert_eq!(engine.beliefs[0].mean, 0.2);
+        assert_eq!(engine.beliefs[1].variance, 2.0);
+    }
+
+    #[test]
+    fn reset_reseeds_an_injected_rng() {
+        let mut engine = engine(Field::with_rng(StdRng::seed_from_u64(1)));
+        let origin = Position { x: 0.0, y: 0.0 };
+        let draws = |engine: &Engine<StdRng>| -> Vec<f64> {
+            (0..4).map(|_| engine.field.observe(&origin)).collect()
+        };
+        engine.reset(42);
+        let first = draws(&engine);
+        engine.reset(42);
+        assert_eq!(first, draws(&engine));
+        engine.reset(43);
+        assert_ne!(first, draws(&engine));
+    }
+
+    #[test]
+    fn on_step_callback_stops_the_run_after_three_steps() {
+        let mut engine = engine(Field::new());
+        let seen = Rc::new(Cell::new(0));
+        let counter = seen.clone();
+        engine.on_step = Some(Box::new(move |_: &StepReport| {
+            counter.set(counter.get() + 1);
+            if counter.get() == 3 { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+        }));
+
+        let reports = engine.run(10);
+        assert_eq!(reports.len(), 3);
+        assert_eq!(seen.get(), 3);
+        assert_eq!(engine.step, 3);
+    }
+
+    #[test]
+    fn sensitivities_match_the_control_law_while_moving() {
+        let mut engine = engine(Field::with_rng(StdRng::seed_from_u64(5)));
+        assert!(engine.step().sensitivity.is_none());
+        for _ in 0..3 {
+            let report = engine.step_with_sensitivity();
+            let [t_mean, t_amp, a_mean, a_amp] = report.sensitivity.expect("reported");
+            // Synth steers with torque = amplitude * (1 - mean), alignment = frequency * mean
+            let fused = report.fused_mean;
+            assert!((t_mean + report.resonance.amplitude).abs() < 1e-6);
+            assert!((t_amp - (1.0 - fused)).abs() < 1e-6);
+            assert!((a_mean - report.resonance.frequency).abs() < 1e-6);
+            assert_eq!(a_amp, 0.0);
+            assert!([t_mean, t_amp, a_mean].iter().all(|s| s.is_finite() && *s != 0.0));
+        }
+        assert!(engine.history.last().unwrap().sensitivity.is_some());
+    }
+
+    #[test]
+    fn disagreement_is_zero_for_agreeing_beliefs_and_grows_apart() {
+        let agreeing = [SimpleBelief::new(0.4, 1.0), SimpleBelief::new(0.4, 3.0)];
+        assert_eq!(belief_disagreement(&agreeing), 0.0);
+
+        let divergent = [SimpleBelief::new(0.0, 1.0), SimpleBelief::new(1.0, 1.0)];
+        assert!((belief_disagreement(&divergent) - 0.25).abs() < 1e-12);
+        assert_eq!(belief_disagreement::<SimpleBelief>(&[]), 0.0);
+    }
+
+    #[test]
+    fn applicators_trace_different_paths_from_the_same_law() {
+        let law = ControlLaw { torque: 0.5, alignment: 1.0 };
+        let drive = |applicator: &dyn ControlApplicator| {
+            (0..4).fold(Position { x: 0.0, y: 0.0 }, |p, _| applicator.apply(p, &law, 1.0))
+        };
+
+        let straight = drive(&HolonomicApplicator);
+        assert_eq!((straight.x, straight.y), (4.0, 2.0));
+
+        let unicycle = UnicycleApplicator::default();
+        let curved = drive(&unicycle);
+        let expected_x: f64 = (1..=4).map(|k| (0.5 * k as f64).cos()).sum();
+        assert!((curved.x - expected_x).abs() < 1e-12);
+        assert!((unicycle.heading.get() - 2.0).abs() < 1e-12);
+
+        unicycle.reset();
+        assert_eq!(unicycle.heading.get(), 0.0);
+    }
+
+    #[test]
+    fn engine_uses_the_injected_applicator() {
+        let run = |control: Box<dyn ControlApplicator>| {
+            let mut engine = engine(Field::with_rng(StdRng::seed_from_u64(9)));
+            engine.control = control;
+            engine.reset(9);
+            trace(&engine.run(3))
+        };
+        let unicycle = run(Box::<UnicycleApplicator>::default());
+        let holonomic = run(Box::new(HolonomicApplicator));
+        assert_ne!(unicycle, holonomic);
+    }
+
+
+    struct LastFusion;
+
+    impl BeliefFusion<SimpleBelief> for LastFusion {
+        fn fuse(beliefs: &[SimpleBelief]) -> SimpleBelief {
+            beliefs.last().cloned().unwrap()
+        }
+    }
+
+    #[test]
+    fn the_configured_belief_fusion_drives_the_fused_mean() {
+        let mut mean = engine(Field::<ThreadNoise>::new());
+        let mut last: SemanticEngine<_, _, _, _, LastFusion> = SemanticEngine::new(
+            mean.beliefs.clone(),
+            Field::<ThreadNoise>::new(),
+            SimpleEntangleMap::new(),
+            Synth,
+            LastFusion,
+            Box::new(EntropyPulse::new(0.5)),
+        );
+        for _ in 0..3 {
+            let from_mean = mean.step().fused_mean;
+            let expected = mean.beliefs.iter().map(|b| b.mean).sum::<f64>() / 2.0;
+            assert!((from_mean - expected).abs() < 1e-12);
+
+            let from_last = last.step().fused_mean;
+            assert_eq!(from_last, last.beliefs[1].mean);
+            assert!((from_last - from_mean).abs() > 1e-6);
+        }
+    }
+
+
+    /// Replays a fixed cycle of raw words, so the noise behind each observation is known.
+    #[derive(Clone)]
+    struct Sequence {
+        words: Vec<u64>,
+        next: usize,
+    }
+
+    impl RngCore for Sequence {
+        fn next_u32(&mut self) -> u32 {
+            (self.next_u64() >> 32) as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            let word = self.words[self.next % self.words.len()];
+            self.next += 1;
+            word
+        }
+
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            rand::rand_core::impls::fill_bytes_via_next(self, dst)
+        }
+    }
+
+    impl SeedableRng for Sequence {
+        type Seed = [u8; 8];
+
+        fn from_seed(seed: Self::Seed) -> Self {
+            Sequence { words: vec![u64::from_le_bytes(seed)], next: 0 }
+        }
+    }
+
+    #[test]
+    fn injected_rng_fixes_each_observation() {
+        // A uniform f64 keeps the top 53 bits: 0 draws 0.0, 1 << 63 draws exactly 0.5
+        let field = Field::with_rng(Sequence { words: vec![0, 1 << 63, u64::MAX], next: 0 });
+        let origin = Position { x: 0.0, y: 0.0 };
+        let observed: Vec<f64> = (0..4).map(|_| field.observe(&origin)).collect();
+        let top = 1.0 - f64::EPSILON / 2.0;
+        assert_eq!(observed, [1.0, 1.0 + 0.1 * 0.5, 1.0 + 0.1 * top, 1.0]);
+
+        let at = Position { x: 0.5, y: 2.0 };
+        let constant = Field::with_rng(Sequence { words: vec![1 << 63], next: 0 });
+        assert_eq!(constant.observe(&at), 0.5_f64.sin() + 2.0_f64.cos() + 0.1 * 0.5);
+    }
+
+
+    #[test]
+    fn belief_literal_takes_the_default_variance_floor() {
+        let belief = SimpleBelief { mean: 0.5, variance: 2.0, ..Default::default() };
+        assert_eq!(belief.variance_floor, SimpleBelief::new(0.5, 2.0).variance_floor);
+        assert_eq!((belief.mean, belief.variance), (0.5, 2.0));
+        let default = SimpleBelief::default();
+        assert_eq!((default.mean, default.variance), (0.0, 1.0));
+    }
+}
@@ -0,0 +1,386 @@
+/// Spectrum module: discrete Fourier transforms for frequency-domain analysis.
+/// Uses a radix-2 FFT for power-of-two lengths and Bluestein's chirp-z algorithm otherwise,
+/// so every length costs O(n log n).
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+/// Computes the discrete Fourier transform of a real signal.
+pub fn fft(signal: &[f64]) -> Vec<Complex64> {
+    let mut data: Vec<Complex64> = signal.iter().map(|&x| Complex64::new(x, 0.0)).collect();
+    transform(&mut data, false);
+    data
+}
+
+/// Computes the inverse discrete Fourier transform, scaled by `1 / n`.
+pub fn ifft(spectrum: &[Complex64]) -> Vec<Complex64> {
+    let mut data = spectrum.to_vec();
+    transform(&mut data, true);
+    let n = data.len() as f64;
+    for v in &mut data {
+        *v /= n;
+    }
+    data
+}
+
+/// Returns the magnitudes of the non-negative frequency bins (`n / 2 + 1` values).
+pub fn magnitude_spectrum(signal: &[f64]) -> Vec<f64> {
+    let half = signal.len() / 2 + 1;
+    fft(signal).iter().take(half).map(|c| c.norm()).collect()
+}
+
+/// Returns the frequency in cycles per unit time of bin `k` for an `n`-point transform.
+pub fn bin_frequency(k: usize, n: usize, sample_rate: f64) -> f64 {
+    k as f64 * sample_rate / n as f64
+}
+
+/// Frequency band kept by `filter_frequency`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FilterKind {
+    LowPass,
+    HighPass,
+    /// Keeps frequencies in `[low, high]`; the `cutoff` argument is ignored.
+    BandPass(f64, f64),
+}
+
+/// Filters a signal by zeroing FFT bins outside the pass band and inverting.
+/// Frequencies are in the same units as `sample_rate`; the cutoff bin is kept.
+pub fn filter_frequency(
+    signal: &[f64],
+    kind: FilterKind,
+    cutoff: f64,
+    sample_rate: f64,
+) -> Vec<f64> {
+    let n = signal.len();
+    let mut spectrum = fft(signal);
+
+    for (k, bin) in spectrum.iter_mut().enumerate() {
+        // Negative-frequency bins mirror their positive counterparts
+        let freq = bin_frequency(k.min(n - k), n, sample_rate);
+        let keep = match kind {
+            FilterKind::LowPass => freq <= cutoff,
+            FilterKind::HighPass => freq >= cutoff,
+            FilterKind::BandPass(low, high) => freq >= low && freq <= high,
+        };
+        if !keep {
+            *bin = Complex64::new(0.0, 0.0);
+        }
+    }
+
+    ifft(&spectrum).iter().map(|c| c.re).collect()
+}
+
+/// Spectral flatness (Wiener entropy): geometric over arithmetic mean of the power spectrum.
+/// Lies in `[0, 1]`; pure tones score near 0 and white noise near 1. Silent input gives 0.
+///
+/// The power spectrum is a Welch average over 8 Hann-windowed segments (when the signal is
+/// long enough); a single raw periodogram would put white noise near 0.56 instead of 1.
+pub fn spectral_flatness(signal: &[f64]) -> f64 {
+    let power = welch_power(signal, 8);
+    if power.is_empty() {
+        return 0.0;
+    }
+    let n = power.len() as f64;
+    let arithmetic = power.iter().sum::<f64>() / n;
+    if arithmetic <= 1e-12 {
+        return 0.0;
+    }
+    // Floor each bin so empty bins pull the geometric mean towards zero instead of -inf
+    let log_mean = power.iter().map(|p| p.max(1e-12).ln()).sum::<f64>() / n;
+    (log_mean.exp() / arithmetic).clamp(0.0, 1.0)
+}
+
+/// Analytic signal via the Hilbert transform: the real part is the input and the imaginary
+/// part its Hilbert transform. Built by zeroing the negative-frequency bins of the FFT.
+pub fn analytic_signal(signal: &[f64]) -> Vec<Complex64> {
+    let n = signal.len();
+    let mut spectrum = fft(signal);
+    for (k, bin) in spectrum.iter_mut().enumerate().skip(1) {
+        // The Nyquist bin of an even-length transform is its own mirror and stays as is
+        if 2 * k < n {
+            *bin *= 2.0;
+        } else if 2 * k > n {
+            *bin = Complex64::new(0.0, 0.0);
+        }
+    }
+    ifft(&spectrum)
+}
+
+/// Instantaneous frequency, in the units of `sample_rate`: the derivative of the unwrapped
+/// phase of the analytic signal. Returns one value per pair of neighbouring samples
+/// (`n - 1` in total), each the frequency between them.
+pub fn instantaneous_frequency(signal: &[f64], sample_rate: f64) -> Vec<f64> {
+    analytic_signal(signal)
+        .windows(2)
+        .map(|w| {
+            // The phase step is the angle of z[i+1] * conj(z[i]), already wrapped to (-pi, pi]
+            let step = (w[1] * w[0].conj()).arg();
+            step * sample_rate / (2.0 * PI)
+        })
+        .collect()
+}
+
+/// Spectral centroid: the power-weighted mean frequency of the one-sided power spectrum,
+/// in the units of `sample_rate`. Silent or empty input gives 0.
+pub fn spectral_centroid(signal: &[f64], sample_rate: f64) -> f64 {
+    power_moments(signal, sample_rate).map_or(0.0, |(centroid, _)| centroid)
+}
+
+/// Spectral bandwidth: the power-weighted standard deviation of frequency around the
+/// centroid, in the units of `sample_rate`. Silent or empty input gives 0.
+pub fn spectral_bandwidth(signal: &[f64], sample_rate: f64) -> f64 {
+    power_moments(signal, sample_rate).map_or(0.0, |(_, bandwidth)| bandwidth)
+}
+
+/// Returns the power-weighted (mean, standard deviation) of bin frequency, or `None`
+/// when the spectrum carries no power.
+fn power_moments(signal: &[f64], sample_rate: f64) -> Option<(f64, f64)> {
+    let n = signal.len();
+    let power: Vec<f64> = magnitude_spectrum(signal).iter().map(|m| m * m).collect();
+    let total: f64 = power.iter().sum();
+    if n == 0 || total <= 1e-12 {
+        return None;
+    }
+    let freqs: Vec<f64> = (0..power.len()).map(|k| bin_frequency(k, n, sample_rate)).collect();
+    let centroid = freqs.iter().zip(&power).map(|(f, p)| f * p).sum::<f64>() / total;
+    let variance = freqs
+        .iter()
+        .zip(&power)
+        .map(|(f, p)| (f - centroid).powi(2) * p)
+        .sum::<f64>()
+        / total;
+    Some((centroid, variance.sqrt()))
+}
+
+/// Averages the power spectra of `segments` non-overlapping Hann-windowed chunks.
+/// Falls back to one unwindowed chunk when the segments would be shorter than 2 samples.
+fn welch_power(signal: &[f64], segments: usize) -> Vec<f64> {
+    let seg_len = signal.len() / segments.max(1);
+    if seg_len < 2 {
+        return magnitude_spectrum(signal).iter().map(|m| m * m).collect();
+    }
+
+    let window: Vec<f64> = (0..seg_len)
+        .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f64 / seg_len as f64).cos())
+        .collect();
+    let mut power = vec![0.0; seg_len / 2 + 1];
+    for chunk in signal.chunks_exact(seg_len) {
+        let windowed: Vec<f64> = chunk.iter().zip(&window).map(|(x, w)| x * w).collect();
+        for (p, m) in power.iter_mut().zip(magnitude_spectrum(&windowed)) {
+            *p += m * m;
+        }
+    }
+    let count = (signal.len() / seg_len) as f64;
+    power.iter().map(|p| p / count).collect()
+}
+
+fn transform(data: &mut [Complex64], inverse: bool) {
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+    if n.is_power_of_two() {
+        radix2(data, inverse);
+    } else {
+        bluestein(data, inverse);
+    }
+}
+
+/// Exact DFT of any length as a power-of-two circular convolution, using
+/// `kt = (k² + t² - (k - t)²) / 2` to turn the twiddles into chirps.
+fn bluestein(data: &mut [Complex64], inverse: bool) {
+    let n = data.len();
+    let m = (2 * n - 1).next_power_of_two();
+    let sign = if inverse { 1.0 } else { -1.0 };
+    // exp(±iπj²/n) repeats every 2n in j², which keeps the phase small for long inputs
+    let chirp: Vec<Complex64> = (0..n)
+        .map(|j| Complex64::from_polar(1.0, sign * PI * ((j * j) % (2 * n)) as f64 / n as f64))
+        .collect();
+
+    let mut a = vec![Complex64::new(0.0, 0.0); m];
+    for ((a, x), c) in a.iter_mut().zip(data.iter()).zip(&chirp) {
+        *a = x * c;
+    }
+    let mut b = vec![Complex64::new(0.0, 0.0); m];
+    b[0] = chirp[0].conj();
+    for j in 1..n {
+        b[j] = chirp[j].conj();
+        b[m - j] = chirp[j].conj();
+    }
+
+    radix2(&mut a, false);
+    radix2(&mut b, false);
+    for (a, b) in a.iter_mut().zip(&b) {
+        *a *= b;
+    }
+    radix2(&mut a, true);
+
+    for ((out, conv), c) in data.iter_mut().zip(&a).zip(&chirp) {
+        *out = conv * c / m as f64;
+    }
+}
+
+/// In-place iterative radix-2 FFT; `data.len()` must be a power of two. Unscaled.
+fn radix2(data: &mut [Complex64], inverse: bool) {
+    let n = data.len();
+    let sign = if inverse { 1.0 } else { -1.0 };
+
+    // Bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let step = Complex64::from_polar(1.0, sign * 2.0 * PI / len as f64);
+        for start in (0..n).step_by(len) {
+            let mut w = Complex64::new(1.0, 0.0);
+            for k in 0..len / 2 {
+                let u = data[start + k];
+                let v = data[start + k + len / 2] * w;
+                data[start + k] = u + v;
+                data[start + k + len / 2] = u - v;
+                w *= step;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_dft(signal: &[f64]) -> Vec<Complex64> {
+        let n = signal.len();
+        (0..n)
+            .map(|k| {
+                signal
+                    .iter()
+                    .enumerate()
+                    .map(|(t, &x)| {
+                        x * Complex64::from_polar(1.0, -2.0 * PI * (k * t) as f64 / n as f64)
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fft_matches_the_dft_for_any_length() {
+        for n in [1, 2, 3, 5, 7, 8, 12, 17, 100] {
+            let signal: Vec<f64> = (0..n).map(|i| ((i * 7 + 3) % 11) as f64 - 5.0).collect();
+            let spectrum = fft(&signal);
+            for (a, b) in spectrum.iter().zip(naive_dft(&signal)) {
+                assert!((a - b).norm() < 1e-9, "n = {n}: {a} != {b}");
+            }
+            for (x, y) in ifft(&spectrum).iter().zip(&signal) {
+                assert!((x.re - y).abs() < 1e-9 && x.im.abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn low_pass_removes_the_high_tone_of_a_two_tone_signal() {
+        // 1 Hz + 12 Hz sampled at 60 Hz over 120 samples (not a power of two)
+        let tone = |hz: f64, i: usize| (2.0 * PI * hz * i as f64 / 60.0).sin();
+        let signal: Vec<f64> = (0..120).map(|i| tone(1.0, i) + tone(12.0, i)).collect();
+        let low = filter_frequency(&signal, FilterKind::LowPass, 5.0, 60.0);
+        let high = filter_frequency(&signal, FilterKind::HighPass, 5.0, 60.0);
+        for (i, (l, h)) in low.iter().zip(&high).enumerate() {
+            assert!((l - tone(1.0, i)).abs() < 1e-9);
+            assert!((h - tone(12.0, i)).abs() < 1e-9);
+        }
+    }
+
+    /// Uniform noise in [-1, 1) from a fixed linear congruential generator.
+    fn noise(len: usize) -> Vec<f64> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                (state >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+            })
+            .collect()
+    }
+
+    #[test]
+    fn spectral_flatness_separates_tones_from_noise() {
+        let tone: Vec<f64> = (0..1024).map(|i| (2.0 * PI * 0.125 * i as f64).sin()).collect();
+        let tone_flatness = spectral_flatness(&tone);
+        let noise_flatness = spectral_flatness(&noise(1024));
+        assert!(tone_flatness < 0.05, "tone: {tone_flatness}");
+        assert!(noise_flatness > 0.8, "noise: {noise_flatness}");
+        assert_eq!(spectral_flatness(&[0.0; 64]), 0.0);
+    }
+
+
+    fn tone(freq: f64, len: usize, sample_rate: f64) -> Vec<f64> {
+        (0..len).map(|i| (std::f64::consts::TAU * freq * i as f64 / sample_rate).sin()).collect()
+    }
+
+    #[test]
+    fn higher_tones_have_higher_centroids() {
+        let (len, rate) = (128, 64.0);
+        let low = spectral_centroid(&tone(4.0, len, rate), rate);
+        let high = spectral_centroid(&tone(20.0, len, rate), rate);
+        assert!((low - 4.0).abs() < 1e-9, "{low}");
+        assert!((high - 20.0).abs() < 1e-9, "{high}");
+        assert_eq!(spectral_centroid(&[0.0; 16], rate), 0.0);
+        assert_eq!(spectral_centroid(&[], rate), 0.0);
+    }
+
+    #[test]
+    fn bandwidth_grows_with_spectral_spread() {
+        let (len, rate) = (128, 64.0);
+        assert!(spectral_bandwidth(&tone(8.0, len, rate), rate) < 1e-6);
+
+        let pair: Vec<f64> = tone(4.0, len, rate)
+            .iter()
+            .zip(tone(20.0, len, rate))
+            .map(|(a, b)| a + b)
+            .collect();
+        assert!((spectral_centroid(&pair, rate) - 12.0).abs() < 1e-9);
+        assert!((spectral_bandwidth(&pair, rate) - 8.0).abs() < 1e-9);
+        assert!(spectral_bandwidth(&noise(len), rate) > 1.0);
+    }
+
+
+    #[test]
+    fn instantaneous_frequency_tracks_a_linear_chirp() {
+        let (n, f0, f1) = (512, 0.05, 0.2);
+        let rate = (f1 - f0) / n as f64;
+        let frequency = instantaneous_frequency(&crate::generators::chirp(f0, f1, n), 1.0);
+        assert_eq!(frequency.len(), n - 1);
+
+        // Away from the edges, where the finite FFT smears the sweep, the estimate follows it
+        let middle = n / 8..7 * n / 8;
+        for i in middle.clone() {
+            let expected = f0 + rate * (i as f64 + 0.5);
+            assert!((frequency[i] - expected).abs() < 0.01, "{i}: {}", frequency[i]);
+        }
+        // Least-squares slope over the middle recovers the sweep rate
+        let (xs, ys): (Vec<f64>, Vec<f64>) = middle.map(|i| (i as f64, frequency[i])).unzip();
+        let mean = |v: &[f64]| v.iter().sum::<f64>() / v.len() as f64;
+        let (mx, my) = (mean(&xs), mean(&ys));
+        let cov: f64 = xs.iter().zip(&ys).map(|(x, y)| (x - mx) * (y - my)).sum();
+        let var: f64 = xs.iter().map(|x| (x - mx) * (x - mx)).sum();
+        assert!((cov / var - rate).abs() < 0.05 * rate, "{}", cov / var);
+    }
+
+    #[test]
+    fn instantaneous_frequency_of_a_tone_is_flat_in_sample_rate_units() {
+        let tone: Vec<f64> = (0..64).map(|i| (2.0 * PI * 0.125 * i as f64).cos()).collect();
+        let frequency = instantaneous_frequency(&tone, 8.0);
+        assert!(frequency.iter().all(|f| (f - 1.0).abs() < 1e-9));
+        assert!(instantaneous_frequency(&[1.0], 8.0).is_empty());
+    }
+}
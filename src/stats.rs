@@ -0,0 +1,55 @@
+//! Stats module: summary statistics shared by the signal, hotspot and field code.
+
+/// Summary statistics of a signal; `std` is the population standard deviation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignalStats {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub std: f64,
+    pub median: f64,
+}
+
+/// Computes summary statistics over the non-NaN samples of `signal`.
+/// Returns `None` if there are none. Even-length medians average the two middle values.
+pub fn signal_stats(signal: &[f64]) -> Option<SignalStats> {
+    let mut values: Vec<f64> = signal.iter().copied().filter(|v| !v.is_nan()).collect();
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(f64::total_cmp);
+
+    let n = values.len();
+    let mean = values.iter().sum::<f64>() / n as f64;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n as f64;
+    let median = if n.is_multiple_of(2) {
+        (values[n / 2 - 1] + values[n / 2]) / 2.0
+    } else {
+        values[n / 2]
+    };
+
+    Some(SignalStats {
+        min: values[0],
+        max: values[n - 1],
+        mean,
+        std: variance.sqrt(),
+        median,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_of_a_known_array() {
+        let stats = signal_stats(&[4.0, 2.0, f64::NAN, 9.0, 5.0, 4.0, 7.0, 5.0, 4.0]).unwrap();
+        assert_eq!(
+            stats,
+            SignalStats { min: 2.0, max: 9.0, mean: 5.0, std: 2.0, median: 4.5 }
+        );
+        assert_eq!(signal_stats(&[3.0, 1.0, 2.0]).unwrap().median, 2.0);
+        assert_eq!(signal_stats(&[]), None);
+        assert_eq!(signal_stats(&[f64::NAN]), None);
+    }
+}
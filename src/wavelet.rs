@@ -1,30 +1,33 @@
 /// Wavelet transform and fusion module.
 /// Provides traits and implementations for wavelet decomposition,
 /// reconstruction, and fusion strategies.
-use omni_wave::*;
-use ndarray::ArrayViewMut1;
-
+pub use crate::primitives::{compute_entropy, haar_reconstruct, haar_transform_into};
+use crate::error::CurvatureError;
+use crate::primitives::{ConvMode, convolve};
+use crate::spectrum::spectral_flatness;
+
+/// Single-level Haar transform of an even-length signal, grouped approximation then detail.
+/// The output is orthonormal, `(a + b) / sqrt(2)` and `(b - a) / sqrt(2)` per pair, so it
+/// preserves signal energy; before 0.2 it was omni-wave's f32 output, smaller by `sqrt(2)`.
+/// Computed in f64 by `haar_transform_into`; panics on an odd-length signal, which
+/// `haar_transform_into` pads instead.
 pub fn haar_transform(signal: &[f64]) -> Vec<f64> {
-    let wavelet = wavelet::HAAR;
-    let signal_len = signal.len();
-    let buffer_len = signal_len + wavelet.window_size() - 2;
-
-    // Convert signal to f32
-    let mut signal_f32: Vec<f32> = signal.iter().map(|&x| x as f32).collect();
-    let mut buffer_f32 = vec![0f32; buffer_len];
-
-    // Convert Vec<f32> to ArrayViewMut1<f32> as required by omni_wave
-    let signal_view = ArrayViewMut1::from_shape(signal_f32.len(), &mut signal_f32[..]).unwrap();
-    let buffer_view = ArrayViewMut1::from_shape(buffer_f32.len(), &mut buffer_f32[..]).unwrap();
-
-    decompose(
-        signal_view,
-        buffer_view,
-        wavelet,
-    );
+    assert!(signal.len().is_multiple_of(2), "haar_transform needs an even-length signal");
+    let mut coeffs = Vec::with_capacity(signal.len());
+    haar_transform_into(signal, &mut coeffs);
+    coeffs
+}
 
-    // Convert result back to f64
-    signal_f32.iter().map(|&x| x as f64).collect()
+/// Applies `haar_transform` to each channel of a vector-valued signal (e.g. x, y, z
+/// curvature components), returning one coefficient vector per channel.
+/// Fails with `TransformError::InvalidLength` unless all channels share a length.
+pub fn haar_transform_multichannel(channels: &[Vec<f64>]) -> Result<Vec<Vec<f64>>, CurvatureError> {
+    if let Some(first) = channels.first()
+        && channels.iter().any(|c| c.len() != first.len())
+    {
+        return Err(TransformError::InvalidLength.into());
+    }
+    Ok(channels.iter().map(|c| haar_transform(c)).collect())
 }
 
 /// A WaveletTransform must satisfy:
@@ -44,18 +47,47 @@ pub trait WaveletTransform {
 #[derive(Debug, Clone)]
 pub struct Signal(Vec<f64>);
 
+impl Signal {
+    pub fn as_slice(&self) -> &[f64] {
+        &self.0
+    }
+}
+
+impl From<Vec<f64>> for Signal {
+    fn from(data: Vec<f64>) -> Self {
+        Signal(data)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Coefficients {
     pub approximation: Vec<f64>,
     pub detail: Vec<f64>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TransformError {
     InvalidLength,
     ReconstructionFailed,
+    /// The basis has no filter for this order.
+    UnsupportedOrder(u8),
+    /// There were no decompositions to fuse.
+    EmptyInput,
+}
+
+impl std::fmt::Display for TransformError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransformError::InvalidLength => write!(f, "signal length is not supported by the transform"),
+            TransformError::ReconstructionFailed => write!(f, "coefficients could not be reconstructed"),
+            TransformError::UnsupportedOrder(order) => write!(f, "wavelet order {order} is not supported"),
+            TransformError::EmptyInput => write!(f, "no decompositions to fuse"),
+        }
+    }
 }
 
+impl std::error::Error for TransformError {}
+
 pub struct HaarWavelet;
 
 impl WaveletTransform for HaarWavelet {
@@ -97,6 +129,63 @@ impl WaveletTransform for HaarWavelet {
     }
 }
 
+impl HaarWavelet {
+    /// Decomposes `levels` times, each level transforming the previous approximation.
+    /// Returns one `Coefficients` per level, finest first; only the last level's
+    /// approximation is needed (with every detail) to reconstruct.
+    /// The signal length must be divisible by `2^levels`.
+    pub fn decompose_n(
+        signal: &Signal,
+        levels: usize,
+    ) -> Result<Vec<Coefficients>, CurvatureError> {
+        let divisor = 1usize.checked_shl(levels as u32).ok_or(TransformError::InvalidLength)?;
+        if signal.0.is_empty() || !signal.0.len().is_multiple_of(divisor) {
+            return Err(TransformError::InvalidLength.into());
+        }
+
+        let mut result = Vec::with_capacity(levels);
+        let mut current = signal.clone();
+        for _ in 0..levels {
+            let coeffs = Self::decompose(&current)?;
+            current = Signal(coeffs.approximation.clone());
+            result.push(coeffs);
+        }
+        Ok(result)
+    }
+
+    /// Like `decompose`, but accepts any length by first extending odd-length signals with
+    /// `padding`. Returns the original length alongside, for `reconstruct_padded`.
+    pub fn decompose_padded(signal: &Signal, padding: PaddingMode) -> (Coefficients, usize) {
+        let padded = Signal(padding.pad_even(&signal.0));
+        let coeffs = Self::decompose(&padded).expect("padded signals have even length");
+        (coeffs, signal.0.len())
+    }
+
+    /// Inverts `decompose_padded`, trimming the reconstruction back to `len` samples.
+    pub fn reconstruct_padded(coeffs: &Coefficients, len: usize) -> Result<Signal, CurvatureError> {
+        let mut signal = Self::reconstruct(coeffs)?;
+        if len > signal.0.len() {
+            return Err(TransformError::ReconstructionFailed.into());
+        }
+        signal.0.truncate(len);
+        Ok(signal)
+    }
+
+    /// Inverts `decompose_n`, rebuilding from the coarsest approximation outward.
+    pub fn reconstruct_n(levels: &[Coefficients]) -> Result<Signal, CurvatureError> {
+        let coarsest = levels.last().ok_or(TransformError::ReconstructionFailed)?;
+        let mut approximation = coarsest.approximation.clone();
+        for level in levels.iter().rev() {
+            let signal = Self::reconstruct(&Coefficients {
+                approximation,
+                detail: level.detail.clone(),
+            })?;
+            approximation = signal.0;
+        }
+        Ok(Signal(approximation))
+    }
+}
+
 /// Represents the wavelet basis used for decomposition and reconstruction.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WaveletBasis {
@@ -148,22 +237,83 @@ impl Default for FusionContext {
         }
     }
 }
+
+impl FusionContext {
+    pub fn builder() -> FusionContextBuilder {
+        FusionContextBuilder::default()
+    }
+}
+
+/// Fluent builder for `FusionContext`; unset fields keep their `Default` values.
+#[derive(Debug, Clone, Default)]
+pub struct FusionContextBuilder {
+    context: FusionContext,
+}
+
+impl FusionContextBuilder {
+    pub fn with_domain_entropy(mut self, entropy: f64) -> Self {
+        self.context.domain_entropy = entropy;
+        self
+    }
+
+    pub fn with_resonance_profile(mut self, profile: Vec<f64>) -> Self {
+        self.context.resonance_profile = Some(profile);
+        self
+    }
+
+    pub fn with_tags<I, T>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.context.semantic_tags = tags.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_coherence_map(mut self, map: Vec<f64>) -> Self {
+        self.context.coherence_map = Some(map);
+        self
+    }
+
+    pub fn with_curvature_profile(mut self, profile: Vec<f64>) -> Self {
+        self.context.curvature_profile = Some(profile);
+        self
+    }
+
+    pub fn with_domain(mut self, label: impl Into<String>) -> Self {
+        self.context.domain_label = Some(label.into());
+        self
+    }
+
+    pub fn build(self) -> FusionContext {
+        self.context
+    }
+}
+
+/// Weights each decomposition by the inverse entropy of its coefficients.
+///
+/// Decompositions of different lengths are zero-padded band by band (see `pad_bands`) to the
+/// longest coefficient length before weighting, so approximation coefficients are only ever
+/// fused with approximation coefficients; entropies use the unpadded coefficients.
 pub struct EntropyWeightedFusion;
 
-impl WaveletFusionStrategy for EntropyWeightedFusion {
-    fn fuse(
+impl EntropyWeightedFusion {
+    /// Like `fuse`, but fails with `TransformError::EmptyInput` when there is nothing to
+    /// fuse instead of returning an empty decomposition.
+    pub fn try_fuse(
         decompositions: &[WaveletDecomposition],
-        _context: &FusionContext,
-    ) -> WaveletDecomposition {
+    ) -> Result<WaveletDecomposition, CurvatureError> {
+        let first = decompositions.first().ok_or(TransformError::EmptyInput)?;
+        let len = decompositions.iter().map(|d| d.coefficients.len()).max().unwrap_or(0);
         let mut total_weight = 0.0;
-        let mut fused_coeffs = vec![0.0; decompositions[0].coefficients.len()];
+        let mut fused_coeffs = vec![0.0; len];
 
         for decomp in decompositions {
             let entropy = compute_entropy(&decomp.coefficients);
             let weight = 1.0 / (entropy + 1e-6); // Avoid division by zero
             total_weight += weight;
 
-            for (i, coeff) in decomp.coefficients.iter().enumerate() {
+            for (i, coeff) in pad_bands(decomp, len).iter().enumerate() {
                 fused_coeffs[i] += coeff * weight;
             }
         }
@@ -172,11 +322,23 @@ impl WaveletFusionStrategy for EntropyWeightedFusion {
             *coeff /= total_weight;
         }
 
-        WaveletDecomposition {
+        Ok(WaveletDecomposition {
             basis: WaveletBasis::Custom("EntropyFused".into()),
             coefficients: fused_coeffs,
-            level: decompositions[0].level,
-        }
+            level: first.level,
+            basis_sources: WaveletDecomposition::fused_sources(decompositions),
+            layout: first.layout,
+        })
+    }
+}
+
+impl WaveletFusionStrategy for EntropyWeightedFusion {
+    /// Returns an empty decomposition (no coefficients, no sources) for an empty slice.
+    fn fuse(
+        decompositions: &[WaveletDecomposition],
+        _context: &FusionContext,
+    ) -> WaveletDecomposition {
+        Self::try_fuse(decompositions).unwrap_or_else(|_| empty_fusion("EntropyFused"))
     }
 
     fn score_basis(
@@ -184,30 +346,92 @@ impl WaveletFusionStrategy for EntropyWeightedFusion {
         signal: &[f64],
         _context: &FusionContext,
     ) -> f64 {
-        let coeffs = match basis {
-            WaveletBasis::Haar => haar_transform(signal),
-            WaveletBasis::Daubechies(order) => daubechies_transform(signal, *order),
-            WaveletBasis::Biorthogonal(a, s) => biorthogonal_transform(signal, *a, *s),
-            WaveletBasis::Custom(name) => custom_transform(signal, name),
-        };
+        let coeffs = basis_transform(signal, basis);
         let entropy = compute_entropy(&coeffs);
         1.0 / (entropy + 1e-6)
     }
 }
 
-pub fn compute_entropy(coeffs: &[f64]) -> f64 {
-    let norm: f64 = coeffs.iter().map(|c| c.abs()).sum();
-    coeffs
-        .iter()
-        .map(|c| {
-            let p = c.abs() / norm;
-            if p > 0.0 {
-                -p * p.log2()
-            } else {
-                0.0
+/// An empty fused decomposition (no coefficients, no sources) labelled `label`.
+fn empty_fusion(label: &str) -> WaveletDecomposition {
+    WaveletDecomposition {
+        basis: WaveletBasis::Custom(label.into()),
+        coefficients: Vec::new(),
+        level: 0,
+        basis_sources: Vec::new(),
+        layout: CoeffLayout::default(),
+    }
+}
+
+/// `decomp`'s coefficients zero-padded to `len`, each band separately: the approximation band
+/// keeps the first `ceil(len / 2)` slots (see `CoeffLayout`) so it never overlaps a longer
+/// decomposition's detail band. Padding never shortens the coefficients.
+fn pad_bands(decomp: &WaveletDecomposition, len: usize) -> Vec<f64> {
+    let coeffs = &decomp.coefficients;
+    let mut padded = vec![0.0; len.max(coeffs.len())];
+    match decomp.layout {
+        // Bands alternate, so trailing zeros extend both at once
+        CoeffLayout::Interleaved => padded[..coeffs.len()].copy_from_slice(coeffs),
+        CoeffLayout::Grouped => {
+            let (approx, detail) = coeffs.split_at(coeffs.len().div_ceil(2));
+            let detail_start = padded.len().div_ceil(2);
+            padded[..approx.len()].copy_from_slice(approx);
+            padded[detail_start..detail_start + detail.len()].copy_from_slice(detail);
+        }
+    }
+    padded
+}
+
+/// Weights bases by tonality: coefficient sets with low spectral flatness
+/// (energy concentrated in few components) get more weight than noise-like ones.
+///
+/// Like `EntropyWeightedFusion`, decompositions of different lengths are zero-padded band by
+/// band to the longest coefficient length; flatness uses the unpadded coefficients.
+pub struct FlatnessWeightedFusion;
+
+impl WaveletFusionStrategy for FlatnessWeightedFusion {
+    /// Returns an empty decomposition (no coefficients, no sources) for an empty slice.
+    fn fuse(
+        decompositions: &[WaveletDecomposition],
+        _context: &FusionContext,
+    ) -> WaveletDecomposition {
+        let Some(first) = decompositions.first() else {
+            return empty_fusion("FlatnessFused");
+        };
+        let len = decompositions.iter().map(|d| d.coefficients.len()).max().unwrap_or(0);
+        let mut total_weight = 0.0;
+        let mut fused_coeffs = vec![0.0; len];
+
+        for decomp in decompositions {
+            let weight = 1.0 - spectral_flatness(&decomp.coefficients) + 1e-6;
+            total_weight += weight;
+
+            for (i, coeff) in pad_bands(decomp, len).iter().enumerate() {
+                fused_coeffs[i] += coeff * weight;
             }
-        })
-        .sum()
+        }
+
+        for coeff in &mut fused_coeffs {
+            *coeff /= total_weight;
+        }
+
+        WaveletDecomposition {
+            basis: WaveletBasis::Custom("FlatnessFused".into()),
+            coefficients: fused_coeffs,
+            level: first.level,
+            basis_sources: WaveletDecomposition::fused_sources(decompositions),
+            layout: first.layout,
+        }
+    }
+
+    fn score_basis(
+        basis: &WaveletBasis,
+        signal: &[f64],
+        _context: &FusionContext,
+    ) -> f64 {
+        let coeffs = basis_transform(signal, basis);
+        1.0 - spectral_flatness(&coeffs)
+    }
 }
 
 /*
@@ -252,18 +476,141 @@ pub fn haar_transform(signal: &[f64]) -> Vec<f64> {
 }
 */
 
-pub fn daubechies_transform(signal: &[f64], order: u8) -> Vec<f64> {
-    let window = order.max(2) as usize;
-    let mut coeffs = Vec::new();
+// Minimum-phase Daubechies scaling (low-pass) filters, normalised to sum to sqrt(2).
+const DB2: [f64; 4] = [
+    0.48296291314453416,
+    0.8365163037378079,
+    0.2241438680420134,
+    -0.12940952255126037,
+];
+const DB4: [f64; 8] = [
+    0.2303778133088965,
+    0.7148465705529157,
+    0.6308807679298589,
+    -0.027983769416859854,
+    -0.18703481171909309,
+    0.030841381835560764,
+    0.0328830116668852,
+    -0.010597401785069032,
+];
+const DB6: [f64; 12] = [
+    0.11154074335010947,
+    0.49462389039845306,
+    0.7511339080210954,
+    0.31525035170919763,
+    -0.22626469396543983,
+    -0.12976686756726194,
+    0.09750160558732304,
+    0.027522865530305727,
+    -0.03158203931748603,
+    0.0005538422011614961,
+    0.004777257510945511,
+    -0.0010773010853084796,
+];
+const DB8: [f64; 16] = [
+    0.05441584224310401,
+    0.31287159091429995,
+    0.6756307362972898,
+    0.5853546836542067,
+    -0.015829105256349306,
+    -0.2840155429615469,
+    0.0004724845739132828,
+    0.12874742662047847,
+    -0.017369301001807547,
+    -0.044088253930794755,
+    0.013981027917398282,
+    0.008746094047405777,
+    -0.004870352993451574,
+    -0.00039174037337694705,
+    0.0006754494064505693,
+    -0.00011747678412476953,
+];
+
+/// Scaling filter for `WaveletBasis::Daubechies(order)`: db2, db4, db6 and db8, where the
+/// order counts vanishing moments and the filter has `2 * order` taps.
+fn daubechies_filter(order: u8) -> Option<&'static [f64]> {
+    match order {
+        2 => Some(&DB2),
+        4 => Some(&DB4),
+        6 => Some(&DB6),
+        8 => Some(&DB8),
+        _ => None,
+    }
+}
 
-    for i in 0..(signal.len().saturating_sub(window)) {
-        let slice = &signal[i..i + window];
-        let weight = 1.0 / window as f64;
-        let avg = slice.iter().map(|x| x * weight).sum::<f64>();
-        coeffs.push(avg);
+/// One level of a two-channel filter bank: correlates the signal with the low-pass `lo`
+/// and its quadrature-mirror high-pass, extending past the edges with `mode`, and keeps
+/// every second output. Each band gets `ceil(n / 2)` coefficients.
+fn filter_bank_analysis(signal: &[f64], lo: &[f64], mode: BoundaryMode) -> Coefficients {
+    let n = signal.len();
+    let taps = lo.len();
+    let half = n.div_ceil(2);
+    let mut approximation = Vec::with_capacity(half);
+    let mut detail = Vec::with_capacity(half);
+
+    for k in 0..half {
+        let (mut a, mut d) = (0.0, 0.0);
+        for (j, &h) in lo.iter().enumerate() {
+            let x = signal[mode.extend((2 * k + j) as isize, n)];
+            // g[j] = (-1)^j h[L - 1 - j]
+            let g = if j % 2 == 0 { lo[taps - 1 - j] } else { -lo[taps - 1 - j] };
+            a += h * x;
+            d += g * x;
+        }
+        approximation.push(a);
+        detail.push(d);
     }
 
-    coeffs
+    Coefficients { approximation, detail }
+}
+
+/// Single-level orthogonal Daubechies DWT with periodic extension, downsampling by two.
+/// Fails with `TransformError::InvalidLength` on odd-length input and
+/// `TransformError::UnsupportedOrder` for orders other than 2, 4, 6 and 8.
+pub fn daubechies_decompose(signal: &[f64], order: u8) -> Result<Coefficients, CurvatureError> {
+    let lo = daubechies_filter(order).ok_or(TransformError::UnsupportedOrder(order))?;
+    if !signal.len().is_multiple_of(2) {
+        return Err(TransformError::InvalidLength.into());
+    }
+    Ok(filter_bank_analysis(signal, lo, BoundaryMode::Periodic))
+}
+
+/// Inverse of `filter_bank_analysis` with periodic extension: each coefficient scatters
+/// its filter taps back onto the samples it was computed from.
+fn filter_bank_synthesis(coeffs: &Coefficients, lo: &[f64]) -> Vec<f64> {
+    let n = 2 * coeffs.approximation.len();
+    let taps = lo.len();
+    let mut signal = vec![0.0; n];
+
+    for (k, (a, d)) in coeffs.approximation.iter().zip(&coeffs.detail).enumerate() {
+        for (j, &h) in lo.iter().enumerate() {
+            let g = if j % 2 == 0 { lo[taps - 1 - j] } else { -lo[taps - 1 - j] };
+            signal[(2 * k + j) % n] += h * a + g * d;
+        }
+    }
+
+    signal
+}
+
+/// Inverts `daubechies_decompose`. Fails with `TransformError::ReconstructionFailed` when
+/// the bands differ in length and `TransformError::UnsupportedOrder` for unknown orders.
+pub fn daubechies_reconstruct(
+    coeffs: &Coefficients,
+    order: u8,
+) -> Result<Vec<f64>, CurvatureError> {
+    let lo = daubechies_filter(order).ok_or(TransformError::UnsupportedOrder(order))?;
+    if coeffs.approximation.len() != coeffs.detail.len() {
+        return Err(TransformError::ReconstructionFailed.into());
+    }
+    Ok(filter_bank_synthesis(coeffs, lo))
+}
+
+/// `daubechies_decompose` flattened to approximation then detail coefficients.
+/// Returns an empty vector when the decomposition fails.
+pub fn daubechies_transform(signal: &[f64], order: u8) -> Vec<f64> {
+    daubechies_decompose(signal, order)
+        .map(|c| [c.approximation, c.detail].concat())
+        .unwrap_or_default()
 }
 
 pub fn biorthogonal_transform(signal: &[f64], a: u8, s: u8) -> Vec<f64> {
@@ -286,6 +633,144 @@ pub fn biorthogonal_transform(signal: &[f64], a: u8, s: u8) -> Vec<f64> {
     coeffs
 }
 
+/// Recovers a signal from `biorthogonal_transform` output. The moving-average transform
+/// drops `a` samples and cannot be inverted exactly, so this returns the minimum-norm
+/// signal (of length `coeffs.len() + max(a, 2)`) whose transform reproduces `coeffs`.
+pub fn biorthogonal_reconstruct(coeffs: &[f64], a: u8, s: u8) -> Vec<f64> {
+    let m = coeffs.len();
+    let n = m + a.max(2) as usize;
+    // Rows of the transform matrix only overlap within this many rows of each other
+    let band = a.max(2) as usize + s.max(2) as usize;
+    let rows: Vec<(usize, Vec<f64>)> = (0..m).map(|i| biorthogonal_row(i, a, s, n)).collect();
+    let gram = |i: usize, j: usize| {
+        let ((si, ri), (sj, rj)) = (&rows[i], &rows[j]);
+        let (lo, hi) = ((*si).max(*sj), (si + ri.len()).min(sj + rj.len()));
+        (lo..hi).map(|k| ri[k - si] * rj[k - sj]).sum::<f64>()
+    };
+
+    // Banded Cholesky factorisation of A A^T, stored as lower[i][i - j]
+    let mut lower = vec![vec![0.0; band + 1]; m];
+    for i in 0..m {
+        for j in i.saturating_sub(band)..=i {
+            let k0 = i.saturating_sub(band).max(j.saturating_sub(band));
+            let sum = gram(i, j) - (k0..j).map(|k| lower[i][i - k] * lower[j][j - k]).sum::<f64>();
+            lower[i][i - j] = if i == j {
+                sum.max(f64::MIN_POSITIVE).sqrt()
+            } else {
+                sum / lower[j][0]
+            };
+        }
+    }
+
+    // Solve L L^T y = c, then x = A^T y
+    let mut y = coeffs.to_vec();
+    for i in 0..m {
+        let sum: f64 = (i.saturating_sub(band)..i).map(|k| lower[i][i - k] * y[k]).sum();
+        y[i] = (y[i] - sum) / lower[i][0];
+    }
+    for i in (0..m).rev() {
+        let sum: f64 = (i + 1..(i + band + 1).min(m)).map(|k| lower[k][k - i] * y[k]).sum();
+        y[i] = (y[i] - sum) / lower[i][0];
+    }
+
+    let mut signal = vec![0.0; n];
+    for ((start, row), weight) in rows.iter().zip(&y) {
+        for (x, r) in signal[*start..].iter_mut().zip(row) {
+            *x += weight * r;
+        }
+    }
+    signal
+}
+
+/// Row `i` of the `biorthogonal_transform` matrix for an `n`-sample signal, as the index
+/// of its first nonzero column and the weights from there on.
+fn biorthogonal_row(i: usize, a: u8, s: u8, n: usize) -> (usize, Vec<f64>) {
+    let analysis_window = a.max(2) as usize;
+    let synthesis_window = s.max(2) as usize;
+    let synth_start = i.saturating_sub(synthesis_window / 2);
+    let synth_end = (synth_start + synthesis_window).min(n);
+    let start = i.min(synth_start);
+    let mut row = vec![0.0; (i + analysis_window).max(synth_end) - start];
+
+    for w in &mut row[i - start..i + analysis_window - start] {
+        *w += 1.0 / (2.0 * analysis_window as f64);
+    }
+    for w in &mut row[synth_start - start..synth_end - start] {
+        *w += 1.0 / (2.0 * synthesis_window as f64);
+    }
+    (start, row)
+}
+
+/// How samples beyond the signal edges are synthesized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BoundaryMode {
+    /// Wraps around: `... x[n-1] | x[0] ... x[n-1] | x[0] ...`
+    #[default]
+    Periodic,
+    /// Mirrors about the edges: `... x[1] x[0] | x[0] ... x[n-1] | x[n-1] x[n-2] ...`
+    Symmetric,
+}
+
+impl BoundaryMode {
+    /// Maps a possibly out-of-range index into `0..n` (`n` must be nonzero).
+    fn extend(self, index: isize, n: usize) -> usize {
+        let n = n as isize;
+        match self {
+            BoundaryMode::Periodic => index.rem_euclid(n) as usize,
+            BoundaryMode::Symmetric => {
+                let m = index.rem_euclid(2 * n);
+                (if m < n { m } else { 2 * n - 1 - m }) as usize
+            }
+        }
+    }
+}
+
+/// Like `daubechies_transform`, but extends the signal past its edges with `mode` rather
+/// than always wrapping. The output has the input's length: odd lengths give
+/// `(n + 1) / 2` approximation and `n / 2` detail coefficients, dropping the detail of the
+/// final pair, which only differences the last sample against the extension.
+/// Unsupported orders give an empty vector.
+pub fn daubechies_transform_with_boundary(
+    signal: &[f64],
+    order: u8,
+    mode: BoundaryMode,
+) -> Vec<f64> {
+    let Some(lo) = daubechies_filter(order) else {
+        return Vec::new();
+    };
+    let Coefficients { approximation, mut detail } = filter_bank_analysis(signal, lo, mode);
+    detail.truncate(signal.len() / 2);
+    [approximation, detail].concat()
+}
+
+/// Like `biorthogonal_transform`, but extends the signal past both edges so the
+/// output has the same length as the input.
+pub fn biorthogonal_transform_with_boundary(
+    signal: &[f64],
+    a: u8,
+    s: u8,
+    mode: BoundaryMode,
+) -> Vec<f64> {
+    let n = signal.len();
+    let analysis_window = a.max(2) as usize;
+    let synthesis_window = s.max(2) as usize;
+    let window_mean = |start: isize, len: usize| {
+        (0..len)
+            .map(|k| signal[mode.extend(start + k as isize, n)])
+            .sum::<f64>()
+            / len as f64
+    };
+
+    (0..n)
+        .map(|i| {
+            let analysis = window_mean(i as isize, analysis_window);
+            let synth_start = i as isize - (synthesis_window / 2) as isize;
+            let synthesis = window_mean(synth_start, synthesis_window);
+            (analysis + synthesis) / 2.0
+        })
+        .collect()
+}
+
 pub fn custom_transform(signal: &[f64], name: &str) -> Vec<f64> {
     match name {
         "identity" => signal.to_vec(),
@@ -295,23 +780,322 @@ pub fn custom_transform(signal: &[f64], name: &str) -> Vec<f64> {
     }
 }
 
+/// Inverts `custom_transform`. The lossy `"pulse"` wavelet fails with
+/// `TransformError::ReconstructionFailed`.
+pub fn custom_reconstruct(coeffs: &[f64], name: &str) -> Result<Vec<f64>, CurvatureError> {
+    match name {
+        "reverse" => Ok(coeffs.iter().rev().cloned().collect()),
+        "pulse" => Err(TransformError::ReconstructionFailed.into()),
+        _ => Ok(coeffs.to_vec()),
+    }
+}
+
+/// Transforms a signal with the given basis. Haar goes through `haar_transform_into`, so odd
+/// lengths are padded as it describes instead of panicking like `haar_transform`.
+pub fn basis_transform(signal: &[f64], basis: &WaveletBasis) -> Vec<f64> {
+    match basis {
+        WaveletBasis::Haar => {
+            let mut coeffs = Vec::with_capacity(signal.len() + 1);
+            haar_transform_into(signal, &mut coeffs);
+            coeffs
+        }
+        WaveletBasis::Daubechies(order) => daubechies_transform(signal, *order),
+        WaveletBasis::Biorthogonal(a, s) => biorthogonal_transform(signal, *a, *s),
+        WaveletBasis::Custom(name) => custom_transform(signal, name),
+    }
+}
+
+/// Measured properties of a basis transform against the `WaveletTransform` contract.
+#[derive(Debug, Clone)]
+pub struct TransformReport {
+    /// Relative error of reconstructing the signal through the transform's adjoint.
+    pub reconstruction_error: f64,
+    /// Coefficient energy divided by signal energy (1.0 for energy-preserving transforms).
+    pub energy_ratio: f64,
+    /// Largest inner product between distinct basis functions, relative to their norms.
+    pub orthogonality_error: f64,
+}
+
+impl TransformReport {
+    /// Returns true when every measured property is within `tolerance` of the ideal.
+    pub fn passes(&self, tolerance: f64) -> bool {
+        self.reconstruction_error <= tolerance
+            && (self.energy_ratio - 1.0).abs() <= tolerance
+            && self.orthogonality_error <= tolerance
+    }
+}
+
+/// Checks reversibility, energy preservation, and orthogonality of a basis on a signal.
+/// The transform is probed with unit impulses to recover its basis functions, so the
+/// measurements assume a linear transform; reconstruction applies the adjoint, which
+/// inverts any orthonormal transform.
+pub fn check_transform_properties(basis: &WaveletBasis, signal: &[f64]) -> TransformReport {
+    let n = signal.len();
+
+    // Column j holds the transform of the j-th unit impulse
+    let columns: Vec<Vec<f64>> = (0..n)
+        .map(|j| {
+            let mut impulse = vec![0.0; n];
+            impulse[j] = 1.0;
+            basis_transform(&impulse, basis)
+        })
+        .collect();
+    let m = columns.first().map_or(0, |c| c.len());
+    let row = |i: usize| columns.iter().map(move |c| c[i]);
+
+    let coeffs = basis_transform(signal, basis);
+    let recon: Vec<f64> = columns
+        .iter()
+        .map(|c| c.iter().zip(&coeffs).map(|(a, b)| a * b).sum())
+        .collect();
+
+    let energy: f64 = signal.iter().map(|x| x * x).sum();
+    let coeff_energy: f64 = coeffs.iter().map(|c| c * c).sum();
+    let residual: f64 = signal
+        .iter()
+        .zip(&recon)
+        .map(|(s, r)| (s - r).powi(2))
+        .sum();
+
+    let norms: Vec<f64> = (0..m).map(|i| row(i).map(|v| v * v).sum::<f64>().sqrt()).collect();
+    let mut orthogonality_error: f64 = 0.0;
+    for i in 0..m {
+        for j in (i + 1)..m {
+            let denom = norms[i] * norms[j];
+            if denom > 0.0 {
+                let dot: f64 = row(i).zip(row(j)).map(|(a, b)| a * b).sum();
+                orthogonality_error = orthogonality_error.max(dot.abs() / denom);
+            }
+        }
+    }
+
+    TransformReport {
+        reconstruction_error: (residual / energy.max(f64::EPSILON)).sqrt(),
+        energy_ratio: coeff_energy / energy.max(f64::EPSILON),
+        orthogonality_error,
+    }
+}
+
+/// Cosine similarity of two signals' coefficient vectors under the given basis.
+/// Coefficients are compared over their common length; returns 0.0 if either is all zero.
+pub fn wavelet_similarity(a: &[f64], b: &[f64], basis: WaveletBasis) -> f64 {
+    cosine_similarity(&basis_transform(a, &basis), &basis_transform(b, &basis))
+}
+
+fn cosine_similarity(a: &[f64], b: &[f64]) -> f64 {
+    let n = a.len().min(b.len());
+    let (a, b) = (&a[..n], &b[..n]);
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+/// Suggests a decomposition level by repeatedly Haar-decomposing the approximation band.
+/// Returns the deepest level whose approximation still holds at least
+/// `target_detail_energy_fraction` of the signal energy; smooth signals keep their energy
+/// in the approximation and so tolerate deeper levels than broadband ones.
+pub fn suggest_level(signal: &[f64], target_detail_energy_fraction: f64) -> usize {
+    let total: f64 = signal.iter().map(|x| x * x).sum();
+    if total == 0.0 {
+        return 0;
+    }
+
+    let mut approx = signal.to_vec();
+    let mut level = 0;
+    while approx.len() >= 2 && approx.len().is_multiple_of(2) {
+        let coeffs = haar_transform(&approx);
+        let next = coeffs[..approx.len() / 2].to_vec();
+        let retained: f64 = next.iter().map(|x| x * x).sum::<f64>() / total;
+        if retained < target_detail_energy_fraction {
+            break;
+        }
+        approx = next;
+        level += 1;
+    }
+
+    level
+}
+
+/// Energy of the detail band at each level `1..=max_level`, from repeatedly transforming
+/// the approximation band. Assumes the basis output is grouped as `[approximation | detail]`
+/// halves, as with `haar_transform`. Stops early once the approximation is shorter than two.
+pub fn energy_by_level(signal: &[f64], basis: &WaveletBasis, max_level: usize) -> Vec<f64> {
+    let mut energies = Vec::with_capacity(max_level);
+    let mut approx = signal.to_vec();
+
+    for _ in 0..max_level {
+        if approx.len() < 2 {
+            break;
+        }
+        let coeffs = basis_transform(&approx, basis);
+        let half = coeffs.len() / 2;
+        energies.push(coeffs[half..].iter().map(|c| c * c).sum());
+        approx = coeffs[..half].to_vec();
+    }
+
+    energies
+}
+
+/// Absolute difference between the signal's energy and the energy of its `levels`-deep
+/// decomposition (every detail band plus the final approximation), splitting bands as in
+/// `energy_by_level`. Near zero for orthogonal bases like Haar and Daubechies on
+/// even-length input; the moving-average biorthogonal stub leaks heavily.
+pub fn level_energy_leakage(signal: &[f64], basis: WaveletBasis, levels: usize) -> f64 {
+    let total: f64 = signal.iter().map(|x| x * x).sum();
+    let mut approx = signal.to_vec();
+    let mut decomposed = 0.0;
+
+    for _ in 0..levels {
+        if approx.len() < 2 {
+            break;
+        }
+        let coeffs = basis_transform(&approx, &basis);
+        let half = coeffs.len() / 2;
+        decomposed += coeffs[half..].iter().map(|c| c * c).sum::<f64>();
+        approx = coeffs[..half].to_vec();
+    }
+    decomposed += approx.iter().map(|c| c * c).sum::<f64>();
+
+    (total - decomposed).abs()
+}
+
+/// Returns the level (1-based) whose detail band holds the most energy,
+/// i.e. the signal's characteristic scale. Returns 0 if no level could be computed.
+pub fn dominant_level(signal: &[f64], basis: WaveletBasis, max_level: usize) -> usize {
+    energy_by_level(signal, &basis, max_level)
+        .iter()
+        .enumerate()
+        .fold((0, f64::NEG_INFINITY), |best, (i, &e)| if e > best.1 { (i + 1, e) } else { best })
+        .0
+}
+
+/// Linearly resamples each signal to `target_len` samples, keeping both endpoints,
+/// so signals of different lengths can be fused coefficient-by-coefficient.
+/// Empty signals resample to zeros.
+pub fn align_signals(signals: &[&[f64]], target_len: usize) -> Vec<Vec<f64>> {
+    signals.iter().map(|s| resample_linear(s, target_len)).collect()
+}
+
+fn resample_linear(signal: &[f64], target_len: usize) -> Vec<f64> {
+    match signal.len() {
+        0 => return vec![0.0; target_len],
+        1 => return vec![signal[0]; target_len],
+        _ => {}
+    }
+    if target_len < 2 {
+        return signal[..target_len].to_vec();
+    }
+
+    let scale = (signal.len() - 1) as f64 / (target_len - 1) as f64;
+    (0..target_len)
+        .map(|j| {
+            let x = j as f64 * scale;
+            let i = (x.floor() as usize).min(signal.len() - 2);
+            let t = x - i as f64;
+            signal[i] + t * (signal[i + 1] - signal[i])
+        })
+        .collect()
+}
+
+/// Removes the DC component, returning the zero-mean signal and the removed mean.
+pub fn remove_mean(signal: &[f64]) -> (Vec<f64>, f64) {
+    if signal.is_empty() {
+        return (vec![], 0.0);
+    }
+    let mean = signal.iter().sum::<f64>() / signal.len() as f64;
+    (signal.iter().map(|x| x - mean).collect(), mean)
+}
+
+/// Restores a mean previously taken out with `remove_mean`.
+pub fn add_mean(signal: &[f64], mean: f64) -> Vec<f64> {
+    signal.iter().map(|x| x + mean).collect()
+}
+
+/// Downsamples a signal by `factor` after low-pass filtering it to suppress aliasing.
+/// Uses a Hamming-windowed sinc FIR with cutoff at the new Nyquist frequency,
+/// replicating edge samples at the boundaries.
+pub fn downsample(signal: &[f64], factor: usize) -> Vec<f64> {
+    if factor <= 1 || signal.is_empty() {
+        return signal.to_vec();
+    }
+
+    let half = 4 * factor; // taps = 8 * factor + 1
+    let cutoff = 0.5 / factor as f64; // cycles per sample
+    let mut taps: Vec<f64> = (0..=2 * half)
+        .map(|k| {
+            let n = k as f64 - half as f64;
+            let sinc = if n == 0.0 {
+                2.0 * cutoff
+            } else {
+                (2.0 * std::f64::consts::PI * cutoff * n).sin() / (std::f64::consts::PI * n)
+            };
+            let window = 0.54
+                - 0.46 * (2.0 * std::f64::consts::PI * k as f64 / (2 * half) as f64).cos();
+            sinc * window
+        })
+        .collect();
+    let gain: f64 = taps.iter().sum();
+    for t in &mut taps {
+        *t /= gain; // unit DC gain
+    }
+
+    // Clamp-extend the edges so every output sees a full window; the taps are symmetric,
+    // so convolving is the same as correlating
+    let last = signal.len() as isize - 1;
+    let padded: Vec<f64> = (-(half as isize)..=last + half as isize)
+        .map(|j| signal[j.clamp(0, last) as usize])
+        .collect();
+    convolve(&padded, &taps, ConvMode::Valid).into_iter().step_by(factor).collect()
+}
+
 
+/// Weights each coefficient index by `FusionContext::resonance_profile`.
+///
+/// Profiles need not match the coefficient length: indices past the end of a shorter
+/// profile reuse its last value, extra entries in a longer one are ignored, and a missing
+/// or empty profile weights every index by 1.0. Decompositions of different lengths are
+/// zero-padded band by band to the longest coefficient length, as in `EntropyWeightedFusion`;
+/// a padded zero still carries its weight, so missing coefficients pull the average down.
 pub struct ResonanceWeightedFusion;
 
+impl ResonanceWeightedFusion {
+    fn weight_at(profile: Option<&Vec<f64>>, i: usize) -> f64 {
+        profile
+            .and_then(|rp| rp.get(i).or(rp.last()))
+            .copied()
+            .unwrap_or(1.0)
+    }
+}
+
 impl WaveletFusionStrategy for ResonanceWeightedFusion {
+    /// Returns an empty decomposition (no coefficients, no sources) for an empty slice.
     fn fuse(
         decompositions: &[WaveletDecomposition],
         context: &FusionContext,
     ) -> WaveletDecomposition {
-        let resonance = context.resonance_profile.as_ref();
-        let len = decompositions[0].coefficients.len();
+        // A (near-)zero resonance profile carries no weighting information, and dividing
+        // by its tiny totals is unstable, so fall back to uniform averaging
+        let resonance = context
+            .resonance_profile
+            .as_ref()
+            .filter(|rp| rp.iter().any(|r| r.abs() > 1e-6));
+        let Some(first) = decompositions.first() else {
+            return empty_fusion("ResonanceFused");
+        };
+        let len = decompositions.iter().map(|d| d.coefficients.len()).max().unwrap_or(0);
         let mut fused = vec![0.0; len];
         let mut total_weight = vec![0.0; len];
 
         for decomp in decompositions {
+            let coefficients = pad_bands(decomp, len);
             for i in 0..len {
-                let r = resonance.map_or(1.0, |rp| rp[i]);
-                fused[i] += decomp.coefficients[i] * r;
+                let r = Self::weight_at(resonance, i);
+                fused[i] += coefficients[i] * r;
                 total_weight[i] += r;
             }
         }
@@ -323,7 +1107,9 @@ impl WaveletFusionStrategy for ResonanceWeightedFusion {
         WaveletDecomposition {
             basis: WaveletBasis::Custom("ResonanceFused".into()),
             coefficients: fused,
-            level: decompositions[0].level,
+            level: first.level,
+            basis_sources: WaveletDecomposition::fused_sources(decompositions),
+            layout: first.layout,
         }
     }
 
@@ -332,52 +1118,95 @@ impl WaveletFusionStrategy for ResonanceWeightedFusion {
         signal: &[f64],
         context: &FusionContext,
     ) -> f64 {
-        let coeffs = match basis {
-            WaveletBasis::Haar => haar_transform(signal),
-            WaveletBasis::Daubechies(order) => daubechies_transform(signal, *order),
-            WaveletBasis::Biorthogonal(a, s) => biorthogonal_transform(signal, *a, *s),
-            WaveletBasis::Custom(name) => custom_transform(signal, name),
-        };
+        let coeffs = basis_transform(signal, basis);
         let resonance = context.resonance_profile.as_ref();
         coeffs
             .iter()
             .enumerate()
             .map(|(i, c)| {
-                let r = resonance.map_or(1.0, |rp| rp[i]);
+                let r = Self::weight_at(resonance, i);
                 r * c.abs()
             })
             .sum::<f64>()
     }
 }
 
-pub struct WaveletEngine<F: WaveletFusionStrategy> {
-    pub basis_set: Vec<WaveletBasis>,
-    pub fusion_strategy: F,
+/// Runs strategy `A` over the decompositions, then feeds its single fused result to `B`.
+///
+/// The second stage sees a one-element set, so it can only reshape that one coefficient
+/// vector: strategies that normalise by their total weight (entropy, flatness and
+/// resonance weighting) pass it through unchanged apart from relabelling. `B` only
+/// changes the result when it transforms coefficients individually. The output takes
+/// `B`'s basis label and keeps the source bases of the original decompositions.
+/// Scoring uses `B`'s `score_basis`.
+pub struct ChainedFusion<A, B> {
+    _stages: std::marker::PhantomData<fn() -> (A, B)>,
 }
 
-impl<F: WaveletFusionStrategy> WaveletEngine<F> {
-    pub fn new(basis_set: Vec<WaveletBasis>, fusion_strategy: F) -> Self {
-        Self { basis_set, fusion_strategy }
+impl<A, B> ChainedFusion<A, B> {
+    pub fn new() -> Self {
+        ChainedFusion {
+            _stages: std::marker::PhantomData,
+        }
     }
+}
 
-    /// Decompose a signal using all bases in the set.
-    pub fn decompose_all(&self, signal: &[f64], level: usize) -> Vec<WaveletDecomposition> {
-        self.basis_set
-            .iter()
-            .map(|basis| {
-                let coeffs = match basis {
-                    WaveletBasis::Haar => haar_transform(signal),
-                    WaveletBasis::Daubechies(order) => daubechies_transform(signal, *order),
-                    WaveletBasis::Biorthogonal(a, s) => biorthogonal_transform(signal, *a, *s),
-                    WaveletBasis::Custom(name) => custom_transform(signal, name),
-                };
-                WaveletDecomposition {
-                    basis: basis.clone(),
-                    coefficients: coeffs,
-                    level,
-                }
-            })
-            .collect()
+impl<A, B> Default for ChainedFusion<A, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A, B> WaveletFusionStrategy for ChainedFusion<A, B>
+where
+    A: WaveletFusionStrategy,
+    B: WaveletFusionStrategy,
+{
+    fn fuse(
+        decompositions: &[WaveletDecomposition],
+        context: &FusionContext,
+    ) -> WaveletDecomposition {
+        let first = A::fuse(decompositions, context);
+        B::fuse(&[first], context)
+    }
+
+    fn score_basis(
+        basis: &WaveletBasis,
+        signal: &[f64],
+        context: &FusionContext,
+    ) -> f64 {
+        B::score_basis(basis, signal, context)
+    }
+}
+
+pub struct WaveletEngine<F: WaveletFusionStrategy> {
+    pub basis_set: Vec<WaveletBasis>,
+    pub fusion_strategy: F,
+}
+
+impl<F: WaveletFusionStrategy> WaveletEngine<F> {
+    pub fn new(basis_set: Vec<WaveletBasis>, fusion_strategy: F) -> Self {
+        Self { basis_set, fusion_strategy }
+    }
+
+    /// Decompose a signal using all bases in the set.
+    pub fn decompose_all(&self, signal: &[f64], level: usize) -> Vec<WaveletDecomposition> {
+        self.decompose_iter(signal, level).collect()
+    }
+
+    /// Lazily decompose a signal with each basis in turn, without collecting the results.
+    pub fn decompose_iter<'a>(
+        &'a self,
+        signal: &'a [f64],
+        level: usize,
+    ) -> impl Iterator<Item = WaveletDecomposition> + 'a {
+        self.basis_set.iter().map(move |basis| WaveletDecomposition {
+            basis: basis.clone(),
+            coefficients: basis_transform(signal, basis),
+            level,
+            basis_sources: vec![basis.clone()],
+            layout: CoeffLayout::Grouped,
+        })
     }
 
     /// Fuse decompositions using the selected strategy.
@@ -386,6 +1215,46 @@ impl<F: WaveletFusionStrategy> WaveletEngine<F> {
         F::fuse(&decompositions, context)
     }
 
+    /// Fuses the decompositions of every (signal, basis) pair into one consensus
+    /// decomposition, signal-major. An ensemble of one signal is the same as `fuse`.
+    /// Signals should share a length unless the strategy aligns lengths itself.
+    pub fn fuse_ensemble(
+        &self,
+        signals: &[&[f64]],
+        context: &FusionContext,
+        level: usize,
+    ) -> WaveletDecomposition {
+        let decompositions: Vec<WaveletDecomposition> = signals
+            .iter()
+            .flat_map(|signal| self.decompose_iter(signal, level))
+            .collect();
+        F::fuse(&decompositions, context)
+    }
+
+    /// Groups bases whose decompositions of `signal` have cosine similarity above
+    /// `similarity_threshold`, so redundant bases can be dropped before fusion.
+    /// Greedy in basis order: each basis joins the first cluster whose leading
+    /// member it resembles, otherwise it starts a new cluster.
+    pub fn cluster_bases(
+        &self,
+        signal: &[f64],
+        similarity_threshold: f64,
+    ) -> Vec<Vec<WaveletBasis>> {
+        let mut clusters: Vec<(Vec<f64>, Vec<WaveletBasis>)> = Vec::new();
+
+        for decomp in self.decompose_iter(signal, 0) {
+            let similar = |lead: &Vec<f64>| {
+                cosine_similarity(lead, &decomp.coefficients) > similarity_threshold
+            };
+            match clusters.iter_mut().find(|(lead, _)| similar(lead)) {
+                Some((_, members)) => members.push(decomp.basis),
+                None => clusters.push((decomp.coefficients, vec![decomp.basis])),
+            }
+        }
+
+        clusters.into_iter().map(|(_, members)| members).collect()
+    }
+
     /// Score each basis for semantic fit.
     pub fn score_bases(&self, signal: &[f64], context: &FusionContext) -> Vec<(WaveletBasis, f64)> {
         self.basis_set
@@ -399,11 +1268,175 @@ impl<F: WaveletFusionStrategy> WaveletEngine<F> {
 }
 
 
+/// Arrangement of approximation (`a`) and detail (`d`) coefficients in a flat vector.
+/// With `n` coefficients the approximation band holds the first `ceil(n / 2)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoeffLayout {
+    /// `[a0, a1, ..., d0, d1, ...]`, as produced by `haar_transform` and `basis_transform`.
+    #[default]
+    Grouped,
+    /// `[a0, d0, a1, d1, ...]`
+    Interleaved,
+}
+
 /// Holds wavelet coefficients and metadata.
 pub struct WaveletDecomposition {
     pub basis: WaveletBasis,
     pub coefficients: Vec<f64>,
     pub level: usize,
+    /// Bases that produced the coefficients: the basis itself for a direct decomposition,
+    /// every contributing basis (in input order) for a fused one.
+    pub basis_sources: Vec<WaveletBasis>,
+    /// How `coefficients` are arranged; fusion assumes all inputs share a layout.
+    pub layout: CoeffLayout,
+}
+
+impl WaveletDecomposition {
+    /// Flattens the provenance of decompositions being fused, so fusing fused
+    /// results still reports the original bases.
+    pub fn fused_sources(decompositions: &[WaveletDecomposition]) -> Vec<WaveletBasis> {
+        decompositions
+            .iter()
+            .flat_map(|d| d.basis_sources.iter().cloned())
+            .collect()
+    }
+
+    /// Returns a copy with coefficients rearranged as `[a0, d0, a1, d1, ...]`.
+    pub fn to_interleaved(&self) -> WaveletDecomposition {
+        let coefficients = match self.layout {
+            CoeffLayout::Interleaved => self.coefficients.clone(),
+            CoeffLayout::Grouped => {
+                let half = self.coefficients.len().div_ceil(2);
+                let (approx, detail) = self.coefficients.split_at(half);
+                let mut out = Vec::with_capacity(self.coefficients.len());
+                for (i, a) in approx.iter().enumerate() {
+                    out.push(*a);
+                    out.extend(detail.get(i));
+                }
+                out
+            }
+        };
+        self.with_layout(coefficients, CoeffLayout::Interleaved)
+    }
+
+    /// Returns a copy with coefficients rearranged as `[a0, a1, ..., d0, d1, ...]`.
+    pub fn to_grouped(&self) -> WaveletDecomposition {
+        let coefficients = match self.layout {
+            CoeffLayout::Grouped => self.coefficients.clone(),
+            CoeffLayout::Interleaved => {
+                let approx = self.coefficients.iter().step_by(2);
+                let detail = self.coefficients.iter().skip(1).step_by(2);
+                approx.chain(detail).copied().collect()
+            }
+        };
+        self.with_layout(coefficients, CoeffLayout::Grouped)
+    }
+
+    /// Inverts the basis transform to recover a time-domain signal. A fused decomposition
+    /// is inverted through its source basis, so fusing across different bases fails with
+    /// `TransformError::ReconstructionFailed`; Haar and Daubechies need an even length.
+    pub fn reconstruct(&self) -> Result<Vec<f64>, CurvatureError> {
+        let basis = match self.basis_sources.split_first() {
+            Some((first, rest)) if rest.iter().all(|b| b == first) => first,
+            Some(_) => return Err(TransformError::ReconstructionFailed.into()),
+            None => &self.basis,
+        };
+        let coeffs = self.to_grouped().coefficients;
+        let even = coeffs.len().is_multiple_of(2);
+
+        match basis {
+            WaveletBasis::Haar if even => Ok(haar_reconstruct(&coeffs)),
+            WaveletBasis::Daubechies(order) if even => {
+                let (approximation, detail) = coeffs.split_at(coeffs.len() / 2);
+                let bands = Coefficients {
+                    approximation: approximation.to_vec(),
+                    detail: detail.to_vec(),
+                };
+                daubechies_reconstruct(&bands, *order)
+            }
+            WaveletBasis::Haar | WaveletBasis::Daubechies(_) => {
+                Err(TransformError::InvalidLength.into())
+            }
+            WaveletBasis::Biorthogonal(a, s) => Ok(biorthogonal_reconstruct(&coeffs, *a, *s)),
+            WaveletBasis::Custom(name) => custom_reconstruct(&coeffs, name),
+        }
+    }
+
+    fn with_layout(&self, coefficients: Vec<f64>, layout: CoeffLayout) -> WaveletDecomposition {
+        WaveletDecomposition {
+            basis: self.basis.clone(),
+            coefficients,
+            level: self.level,
+            basis_sources: self.basis_sources.clone(),
+            layout,
+        }
+    }
+
+    /// Quantizes coefficients to `bits`-bit integer codes over their observed range.
+    /// `bits` is clamped to 1..=32. Each dequantized coefficient lies within
+    /// `(max - min) / (2 * (2^bits - 1))` of the original.
+    pub fn quantize(&self, bits: u8) -> QuantizedDecomposition {
+        let bits = bits.clamp(1, 32);
+        let levels = ((1u64 << bits) - 1) as f64;
+        let min = self.coefficients.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = self.coefficients.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let range = max - min;
+
+        let codes = self
+            .coefficients
+            .iter()
+            .map(|c| {
+                if range > 0.0 {
+                    ((c - min) / range * levels).round() as u32
+                } else {
+                    0
+                }
+            })
+            .collect();
+
+        QuantizedDecomposition {
+            basis: self.basis.clone(),
+            basis_sources: self.basis_sources.clone(),
+            layout: self.layout,
+            level: self.level,
+            bits,
+            min: if min.is_finite() { min } else { 0.0 },
+            max: if max.is_finite() { max } else { 0.0 },
+            codes,
+        }
+    }
+}
+
+/// Lossy integer encoding of a `WaveletDecomposition`.
+#[derive(Debug, Clone)]
+pub struct QuantizedDecomposition {
+    pub basis: WaveletBasis,
+    pub basis_sources: Vec<WaveletBasis>,
+    pub layout: CoeffLayout,
+    pub level: usize,
+    pub bits: u8,
+    pub min: f64,
+    pub max: f64,
+    pub codes: Vec<u32>,
+}
+
+impl QuantizedDecomposition {
+    /// Maps the integer codes back to coefficients over the stored range.
+    pub fn dequantize(&self) -> WaveletDecomposition {
+        let levels = ((1u64 << self.bits) - 1) as f64;
+        let step = (self.max - self.min) / levels;
+        WaveletDecomposition {
+            basis: self.basis.clone(),
+            coefficients: self
+                .codes
+                .iter()
+                .map(|&q| self.min + q as f64 * step)
+                .collect(),
+            level: self.level,
+            basis_sources: self.basis_sources.clone(),
+            layout: self.layout,
+        }
+    }
 }
 
 
@@ -411,11 +1444,99 @@ pub struct WaveletDecomposition {
 pub struct WaveletTransformStruct {
     pub levels: usize,
     pub threshold: f64,
+    /// How detail coefficients are thresholded in `smooth`.
+    pub mode: ThresholdMode,
+    /// How odd-length signals are extended to an even length before smoothing.
+    pub padding: PaddingMode,
+}
+
+/// How odd-length signals are extended by one sample for the Haar transforms in
+/// `WaveletTransformStruct::smooth` and `HaarWavelet::decompose_padded`; the extra sample is
+/// trimmed off again after reconstruction.
+///
+/// `ZeroPad` adds no energy, so the coefficient energy still matches the signal's.
+/// `Symmetric` and `Periodic` repeat an existing sample, adding its energy, but avoid the
+/// artificial jump to zero at the end of the signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PaddingMode {
+    /// Appends zeros.
+    #[default]
+    ZeroPad,
+    /// Mirrors about the end: `x[n-1] x[n-2] ...`
+    Symmetric,
+    /// Wraps around to the start: `x[0] x[1] ...`
+    Periodic,
+}
+
+impl PaddingMode {
+    /// Extends `signal` to `len` samples; longer signals are returned unchanged.
+    pub fn pad(&self, signal: &[f64], len: usize) -> Vec<f64> {
+        let n = signal.len();
+        (0..len.max(n))
+            .map(|i| match self {
+                _ if i < n => signal[i],
+                PaddingMode::ZeroPad => 0.0,
+                PaddingMode::Symmetric => signal[BoundaryMode::Symmetric.extend(i as isize, n)],
+                PaddingMode::Periodic => signal[BoundaryMode::Periodic.extend(i as isize, n)],
+            })
+            .collect()
+    }
+
+    /// Pads `signal` to the next even length.
+    fn pad_even(&self, signal: &[f64]) -> Vec<f64> {
+        self.pad(signal, signal.len().next_multiple_of(2))
+    }
+}
+
+/// Detail-coefficient thresholding rule for `WaveletTransformStruct::smooth`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThresholdMode {
+    /// Zeroes coefficients with `|d| <= threshold` and keeps the rest unchanged.
+    #[default]
+    Hard,
+    /// Shrinks every coefficient toward zero by the threshold:
+    /// `sign(d) * max(|d| - threshold, 0)`. Avoids the jumps hard thresholding leaves.
+    Soft,
+}
+
+impl ThresholdMode {
+    /// Applies the rule to one detail coefficient.
+    pub fn apply(&self, detail: f64, threshold: f64) -> f64 {
+        match self {
+            ThresholdMode::Hard => {
+                if detail.abs() > threshold { detail } else { 0.0 }
+            }
+            ThresholdMode::Soft => detail.signum() * (detail.abs() - threshold).max(0.0),
+        }
+    }
 }
 
 impl WaveletTransformStruct {
+    /// Creates a smoother with hard thresholding and zero padding.
+    pub fn new(levels: usize, threshold: f64) -> Self {
+        WaveletTransformStruct {
+            levels,
+            threshold,
+            mode: ThresholdMode::default(),
+            padding: PaddingMode::default(),
+        }
+    }
+
+    /// Sets how detail coefficients are thresholded.
+    pub fn with_mode(mut self, mode: ThresholdMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets how odd-length signals are extended before smoothing.
+    pub fn with_padding(mut self, padding: PaddingMode) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Odd-length signals are padded with `padding` and trimmed back to their length.
     pub fn smooth(&self, signal: &[f64]) -> Vec<f64> {
-        let mut data = signal.to_vec();
+        let mut data = self.padding.pad_even(signal);
         let mut temp = vec![0.0; data.len()];
 
         for _ in 0..self.levels {
@@ -425,14 +1546,14 @@ impl WaveletTransformStruct {
                 let diff = (data[i] - data[i + 1]) / 2.0;
 
                 temp[i / 2] = avg;
-                temp[data.len() / 2 + i / 2] = if diff.abs() > self.threshold { diff } else { 0.0 };
+                temp[data.len() / 2 + i / 2] = self.mode.apply(diff, self.threshold);
                 i += 2;
             }
             data = temp.clone();
         }
 
         // Reconstruct smoothed signal
-        let mut recon = vec![0.0; signal.len()];
+        let mut recon = vec![0.0; data.len()];
         let mut i = 0;
         while i + 1 < recon.len() {
             let avg = data[i / 2];
@@ -442,7 +1563,813 @@ impl WaveletTransformStruct {
             i += 2;
         }
 
+        recon.truncate(signal.len());
         recon
     }
+
+    /// Low-pass trend: the reconstruction from the `levels`-deep Haar approximation with
+    /// every detail coefficient zeroed, which replaces each block of `2^levels` samples by
+    /// its mean. The signal is extended symmetrically to a whole number of blocks and
+    /// trimmed back, whatever `padding` says, since zero padding would drag the last block
+    /// toward zero. Levels beyond the signal's length collapse to a single block.
+    pub fn approximation(&self, signal: &[f64]) -> Vec<f64> {
+        let mut block = 1;
+        for _ in 0..self.levels {
+            if block >= signal.len() {
+                break;
+            }
+            block *= 2;
+        }
+
+        let padded = PaddingMode::Symmetric.pad(signal, signal.len().next_multiple_of(block));
+        let mut trend: Vec<f64> = padded
+            .chunks(block)
+            .flat_map(|chunk| {
+                let mean = chunk.iter().sum::<f64>() / chunk.len() as f64;
+                std::iter::repeat_n(mean, chunk.len())
+            })
+            .collect();
+        trend.truncate(signal.len());
+        trend
+    }
+}
+
+/// One level with a zero threshold, which hands signals back unchanged.
+impl Default for WaveletTransformStruct {
+    fn default() -> Self {
+        WaveletTransformStruct::new(1, 0.0)
+    }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: &[f64], b: &[f64], tol: f64) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() < tol, "{x} != {y}");
+        }
+    }
+
+    #[test]
+    fn haar_transform_into_matches_haar_transform_on_even_input() {
+        let signal = [1.0, 3.0, -2.0, 0.5, 4.0, 4.0, 0.25, -1.0];
+        let mut out = vec![0.0; 3];
+        haar_transform_into(&signal, &mut out);
+        assert_eq!(out, haar_transform(&signal));
+    }
+
+    fn total_variation(signal: &[f64]) -> f64 {
+        signal.windows(2).map(|w| (w[1] - w[0]).abs()).sum()
+    }
+
+    #[test]
+    fn soft_thresholding_shrinks_what_hard_thresholding_keeps() {
+        let noisy_step = [0.02, -0.03, 0.3, -0.25, 1.04, 0.97, 1.4, 0.6];
+        let hard = WaveletTransformStruct::new(1, 0.1).smooth(&noisy_step);
+        let soft = WaveletTransformStruct::new(1, 0.1)
+            .with_mode(ThresholdMode::Soft)
+            .smooth(&noisy_step);
+
+        // Small in-pair wiggles are removed by both; large ones survive hard thresholding
+        // intact but lose the threshold under soft thresholding
+        assert_close(&hard[..2], &[-0.005, -0.005], 1e-12);
+        assert_close(&soft[..2], &[-0.005, -0.005], 1e-12);
+        assert_close(&hard[6..], &[1.4, 0.6], 1e-12);
+        assert_close(&soft[6..], &[1.3, 0.7], 1e-12);
+        assert!(total_variation(&soft) < total_variation(&hard));
+        assert_eq!(WaveletTransformStruct::new(1, 0.1).mode, ThresholdMode::Hard);
+    }
+
+    fn ramp(len: usize) -> Vec<f64> {
+        (0..len).map(|i| (i as f64 * 0.7).sin() + i as f64 * 0.1).collect()
+    }
+
+    const PADDINGS: [PaddingMode; 3] =
+        [PaddingMode::ZeroPad, PaddingMode::Symmetric, PaddingMode::Periodic];
+
+    #[test]
+    fn smooth_handles_arbitrary_lengths() {
+        for len in [7, 13, 100] {
+            let signal = ramp(len);
+            for padding in PADDINGS {
+                let smoother = WaveletTransformStruct::new(1, 0.0).with_padding(padding);
+                let smoothed = smoother.smooth(&signal);
+                // A zero threshold keeps every detail, so one level reconstructs exactly
+                assert_close(&smoothed, &signal, 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn haar_padded_round_trips_arbitrary_lengths() {
+        for len in [7, 13, 100] {
+            let signal = ramp(len);
+            for padding in PADDINGS {
+                let (coeffs, n) = HaarWavelet::decompose_padded(&Signal(signal.clone()), padding);
+                assert_eq!(n, len);
+                let recon = HaarWavelet::reconstruct_padded(&coeffs, n).unwrap();
+                assert_close(recon.as_slice(), &signal, 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn zero_padding_preserves_energy() {
+        let signal = ramp(13);
+        let padded = HaarWavelet::decompose_padded(&Signal(signal.clone()), PaddingMode::ZeroPad);
+        let coeffs = padded.0;
+        let energy = |xs: &[f64]| xs.iter().map(|x| x * x).sum::<f64>();
+        // The averaging Haar pair (a, d) carries half the energy of the samples it replaces
+        let coeff_energy = 2.0 * (energy(&coeffs.approximation) + energy(&coeffs.detail));
+        assert!((coeff_energy - energy(&signal)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn boundary_transforms_preserve_length() {
+        for len in [1, 2, 7, 8, 13, 64] {
+            let signal = ramp(len);
+            for mode in [BoundaryMode::Periodic, BoundaryMode::Symmetric] {
+                for order in [2, 4, 6, 8] {
+                    let out = daubechies_transform_with_boundary(&signal, order, mode);
+                    assert_eq!(out.len(), len, "db{order} {mode:?} on {len} samples");
+                }
+                let out = biorthogonal_transform_with_boundary(&signal, 2, 2, mode);
+                assert_eq!(out.len(), len, "bior {mode:?} on {len} samples");
+            }
+        }
+        assert!(daubechies_transform_with_boundary(&ramp(8), 3, BoundaryMode::Periodic).is_empty());
+    }
+
+    #[test]
+    fn approximation_tracks_a_noisy_ramp() {
+        let noise = [0.05, -0.04, 0.03, -0.05, 0.02, -0.03, 0.04, -0.02];
+        let signal: Vec<f64> = (0..64).map(|i| 0.5 * i as f64 + noise[i % 8]).collect();
+        let trend = WaveletTransformStruct::new(2, 0.0).approximation(&signal);
+        assert_eq!(trend.len(), signal.len());
+        for (i, t) in trend.iter().enumerate() {
+            // Each block of four is replaced by its mean, at most 0.75 off the line
+            assert!((t - 0.5 * i as f64).abs() <= 0.8, "sample {i}: {t}");
+        }
+    }
+
+    #[test]
+    fn approximation_keeps_edge_blocks_near_the_signal() {
+        let ramp: Vec<f64> = (1..=9).map(f64::from).collect();
+        for padding in PADDINGS {
+            let trend = WaveletTransformStruct::new(2, 0.0)
+                .with_padding(padding)
+                .approximation(&ramp);
+            assert_close(&trend[..4], &[2.5; 4], 1e-12);
+            // The lone last sample is averaged with its mirror images 9, 8, 7
+            assert!((trend[8] - 8.25).abs() < 1e-12);
+        }
+    }
+
+    fn decomposition(basis: WaveletBasis, coefficients: Vec<f64>) -> WaveletDecomposition {
+        WaveletDecomposition {
+            basis_sources: vec![basis.clone()],
+            basis,
+            coefficients,
+            level: 1,
+            layout: CoeffLayout::Grouped,
+        }
+    }
+
+    #[test]
+    fn fusion_strategies_return_an_empty_decomposition_for_no_input() {
+        let context = FusionContext::default();
+        for fused in [
+            EntropyWeightedFusion::fuse(&[], &context),
+            FlatnessWeightedFusion::fuse(&[], &context),
+            ResonanceWeightedFusion::fuse(&[], &context),
+        ] {
+            assert!(fused.coefficients.is_empty());
+            assert!(fused.basis_sources.is_empty());
+        }
+    }
+
+    #[test]
+    fn fusion_strategies_align_decompositions_of_different_lengths() {
+        let context = FusionContext::default();
+        // Approximation coefficients are 1.0 and details 3.0 in both decompositions
+        let short = || decomposition(WaveletBasis::Haar, vec![1.0, 3.0]);
+        let long = || decomposition(WaveletBasis::Daubechies(4), vec![1.0, 1.0, 1.0, 3.0, 3.0]);
+        for fused in [
+            FlatnessWeightedFusion::fuse(&[short(), long()], &context),
+            FlatnessWeightedFusion::fuse(&[long(), short()], &context),
+            ResonanceWeightedFusion::fuse(&[short(), long()], &context),
+            ResonanceWeightedFusion::fuse(&[long(), short()], &context),
+        ] {
+            let c = &fused.coefficients;
+            assert_eq!(c.len(), 5);
+            // Slots both decompositions fill keep their band's value; the rest are scaled
+            // down by the short decomposition's padded zeros, equally in both bands
+            assert!((c[0] - 1.0).abs() < 1e-9 && (c[3] - 3.0).abs() < 1e-9, "{c:?}");
+            assert!((c[1] - c[2]).abs() < 1e-12 && (c[4] - 3.0 * c[1]).abs() < 1e-9, "{c:?}");
+            assert!(c[1] > 0.0 && c[1] < 1.0, "{c:?}");
+            assert_eq!(fused.basis_sources.len(), 2);
+        }
+    }
+
+    #[test]
+    fn pad_bands_keeps_approximation_and_detail_apart() {
+        let mut short = decomposition(WaveletBasis::Haar, vec![1.0, 2.0, 3.0, 10.0, 20.0]);
+        assert_eq!(pad_bands(&short, 8), [1.0, 2.0, 3.0, 0.0, 10.0, 20.0, 0.0, 0.0]);
+        assert_eq!(pad_bands(&short, 3), short.coefficients);
+
+        short.layout = CoeffLayout::Interleaved;
+        assert_eq!(pad_bands(&short, 7), [1.0, 2.0, 3.0, 10.0, 20.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn downsample_attenuates_components_that_would_alias() {
+        let tone = |cycles: f64| -> Vec<f64> {
+            (0..256).map(|i| (2.0 * std::f64::consts::PI * cycles * i as f64).sin()).collect()
+        };
+        let peak = |s: &[f64]| s[8..s.len() - 8].iter().fold(0.0_f64, |m, x| m.max(x.abs()));
+
+        // 0.4 cycles/sample would fold down to 0.1 after keeping every 4th sample
+        let high = downsample(&tone(0.4), 4);
+        assert_eq!(high.len(), 64);
+        assert!(peak(&high) < 0.05, "aliased peak {}", peak(&high));
+
+        // Well below the new Nyquist frequency (0.125) the tone passes through
+        let low = downsample(&tone(0.02), 4);
+        assert!(peak(&low) > 0.9);
+    }
+
+    #[test]
+    fn transform_properties_separate_orthonormal_bases_from_moving_averages() {
+        let signal = [1.0, 3.0, -2.0, 0.5, 4.0, 4.0, 0.25, -1.0];
+        let haar = check_transform_properties(&WaveletBasis::Haar, &signal);
+        assert!(haar.passes(1e-9), "{haar:?}");
+        let db = check_transform_properties(&WaveletBasis::Daubechies(2), &signal);
+        assert!(db.passes(1e-9), "{db:?}");
+
+        let moving_average = check_transform_properties(&WaveletBasis::Biorthogonal(2, 2), &signal);
+        assert!(!moving_average.passes(1e-3));
+        assert!(moving_average.reconstruction_error > 1e-3);
+    }
+
+    #[test]
+    fn suggest_level_goes_deeper_on_smooth_signals() {
+        let smooth: Vec<f64> =
+            (0..64).map(|i| (2.0 * std::f64::consts::PI * i as f64 / 64.0).sin()).collect();
+        let alternating: Vec<f64> = (0..64).map(|i| if i % 2 == 0 { 1.0 } else { -1.0 }).collect();
+
+        // A slow sine keeps its energy in the approximation band for several halvings,
+        // while an alternating signal has none left after the first
+        assert!(suggest_level(&smooth, 0.9) >= 3);
+        assert_eq!(suggest_level(&alternating, 0.9), 0);
+        assert_eq!(suggest_level(&[0.0; 8], 0.9), 0);
+    }
+
+    #[test]
+    fn decompose_iter_yields_the_collected_decompositions() {
+        let engine = WaveletEngine::new(
+            vec![WaveletBasis::Haar, WaveletBasis::Daubechies(4), WaveletBasis::Biorthogonal(2, 3)],
+            EntropyWeightedFusion,
+        );
+        let signal = ramp(16);
+        let collected = engine.decompose_all(&signal, 2);
+        let streamed: Vec<_> = engine.decompose_iter(&signal, 2).collect();
+
+        assert_eq!(streamed.len(), collected.len());
+        for (s, c) in streamed.iter().zip(&collected) {
+            assert_eq!(s.basis, c.basis);
+            assert_eq!(s.level, 2);
+            assert_eq!(s.coefficients, c.coefficients);
+        }
+    }
+
+    #[test]
+    fn wavelet_similarity_is_high_for_a_smoothed_copy_and_low_for_unrelated_signals() {
+        let noise = [0.1, -0.08, 0.05, -0.12, 0.07, -0.03, 0.09, -0.06];
+        let signal: Vec<f64> = (0..64)
+            .map(|i| (2.0 * std::f64::consts::PI * i as f64 / 32.0).sin() + noise[i % 8])
+            .collect();
+        let smoothed: Vec<f64> = (0..64usize)
+            .map(|i| signal[i.saturating_sub(1)..(i + 2).min(64)].iter().sum::<f64>() / 3.0)
+            .collect();
+        let unrelated: Vec<f64> =
+            (0..64).map(|i| (2.0 * std::f64::consts::PI * i as f64 / 32.0).cos()).collect();
+
+        let basis = WaveletBasis::Daubechies(2);
+        assert!(wavelet_similarity(&signal, &smoothed, basis.clone()) > 0.95);
+        assert!(wavelet_similarity(&signal, &unrelated, basis).abs() < 0.2);
+    }
+
+    #[test]
+    fn quantization_error_shrinks_as_bits_grow() {
+        let coefficients: Vec<f64> = (0..32).map(|i| (i as f64 * 0.7).sin() * 3.0).collect();
+        let decomp = decomposition(WaveletBasis::Haar, coefficients.clone());
+        let max_error = |bits: u8| {
+            let restored = decomp.quantize(bits).dequantize().coefficients;
+            restored.iter().zip(&coefficients).map(|(r, c)| (r - c).abs()).fold(0.0, f64::max)
+        };
+
+        let errors: Vec<f64> = [2, 4, 8, 16].map(max_error).to_vec();
+        assert!(errors.windows(2).all(|w| w[1] < w[0]), "{errors:?}");
+        // Each code covers range / (2^bits - 1), so rounding is off by at most half a step
+        assert!(errors[2] <= 6.0 / 255.0 / 2.0 + 1e-12);
+    }
+
+    #[test]
+    fn zero_resonance_profile_falls_back_to_the_plain_mean() {
+        let decompositions = [
+            decomposition(WaveletBasis::Haar, vec![1.0, -2.0, 4.0]),
+            decomposition(WaveletBasis::Daubechies(2), vec![3.0, 0.0, -4.0]),
+        ];
+        for profile in [vec![0.0; 3], vec![1e-9, 0.0, -1e-9]] {
+            let context = FusionContext::builder().with_resonance_profile(profile).build();
+            let fused = ResonanceWeightedFusion::fuse(&decompositions, &context);
+            assert_close(&fused.coefficients, &[2.0, -1.0, 0.0], 1e-12);
+        }
+    }
+
+    #[test]
+    fn dominant_level_matches_the_period_of_a_square_wave() {
+        let square = |period: usize| -> Vec<f64> {
+            (0..64).map(|i| if i % period < period / 2 { 1.0 } else { -1.0 }).collect()
+        };
+        // A period of 2^k samples only shows up in the level-k detail band
+        assert_eq!(dominant_level(&square(2), WaveletBasis::Haar, 5), 1);
+        assert_eq!(dominant_level(&square(8), WaveletBasis::Haar, 5), 3);
+        assert_eq!(dominant_level(&square(16), WaveletBasis::Haar, 5), 4);
+        assert_eq!(dominant_level(&[], WaveletBasis::Haar, 5), 0);
+    }
+
+    #[test]
+    fn align_signals_resamples_to_a_common_length() {
+        let short = [0.0, 2.0, 4.0];
+        let long = [1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0];
+        let aligned = align_signals(&[&short, &long, &[], &[7.0]], 5);
+
+        assert!(aligned.iter().all(|s| s.len() == 5));
+        assert_close(&aligned[0], &[0.0, 1.0, 2.0, 3.0, 4.0], 1e-12);
+        assert_close(&aligned[1], &[1.0, 1.75, 2.5, 3.25, 4.0], 1e-12);
+        assert_close(&aligned[2], &[0.0; 5], 1e-12);
+        assert_close(&aligned[3], &[7.0; 5], 1e-12);
+    }
+
+    #[test]
+    fn fusion_context_builder_sets_every_field() {
+        let context = FusionContext::builder()
+            .with_domain_entropy(0.7)
+            .with_resonance_profile(vec![1.0, 0.5])
+            .with_tags(["tidal", "slow"])
+            .with_coherence_map(vec![0.9])
+            .with_curvature_profile(vec![0.1, 0.2, 0.3])
+            .with_domain("biological")
+            .build();
+
+        assert_eq!(context.domain_entropy, 0.7);
+        assert_eq!(context.resonance_profile, Some(vec![1.0, 0.5]));
+        assert_eq!(context.semantic_tags, ["tidal", "slow"]);
+        assert_eq!(context.coherence_map, Some(vec![0.9]));
+        assert_eq!(context.curvature_profile, Some(vec![0.1, 0.2, 0.3]));
+        assert_eq!(context.domain_label.as_deref(), Some("biological"));
+
+        let empty = FusionContext::builder().build();
+        assert!(empty.resonance_profile.is_none() && empty.semantic_tags.is_empty());
+    }
+
+    #[test]
+    fn three_level_haar_round_trip_recovers_the_signal() {
+        let data = vec![
+            4.0, -1.5, 2.25, 8.0, 0.0, 3.0, -6.5, 1.0, 2.0, 2.0, 5.5, -3.0, 7.0, 0.5, 1.0, 9.0,
+        ];
+        let levels = HaarWavelet::decompose_n(&Signal::from(data.clone()), 3).unwrap();
+        assert_eq!(levels.len(), 3);
+        assert_eq!(levels[2].approximation.len(), 2);
+        let restored = HaarWavelet::reconstruct_n(&levels).unwrap();
+        assert_close(restored.as_slice(), &data, 1e-12);
+
+        let uneven = HaarWavelet::decompose_n(&Signal::from(vec![1.0; 12]), 3);
+        assert!(matches!(uneven, Err(CurvatureError::Transform(TransformError::InvalidLength))));
+    }
+
+    #[test]
+    fn removing_and_restoring_the_mean_round_trips() {
+        let signal = [3.0, 5.0, 4.0, 8.0];
+        let (centred, mean) = remove_mean(&signal);
+        assert_eq!(mean, 5.0);
+        assert_close(&centred, &[-2.0, 0.0, -1.0, 3.0], 1e-12);
+        assert_close(&add_mean(&centred, mean), &signal, 1e-12);
+        assert_eq!(remove_mean(&[]), (vec![], 0.0));
+    }
+
+    #[test]
+    fn near_identical_bases_cluster_together() {
+        // Unknown custom names fall back to the identity transform
+        let identity = WaveletBasis::Custom("identity".into());
+        let fallback = WaveletBasis::Custom("passthrough".into());
+        let reverse = WaveletBasis::Custom("reverse".into());
+        let engine = WaveletEngine::new(
+            vec![identity.clone(), reverse.clone(), fallback.clone()],
+            EntropyWeightedFusion,
+        );
+        let centred_ramp: Vec<f64> = (0..16).map(|i| i as f64 - 7.5).collect();
+
+        let clusters = engine.cluster_bases(&centred_ramp, 0.95);
+        assert_eq!(clusters, vec![vec![identity, fallback], vec![reverse]]);
+    }
+
+    #[test]
+    fn flatness_fusion_favours_tonal_decompositions() {
+        let tone: Vec<f64> =
+            (0..256).map(|i| (2.0 * std::f64::consts::PI * 0.125 * i as f64).sin()).collect();
+        let mut state = 7u32;
+        let noise: Vec<f64> = (0..256)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state as f64 / u32::MAX as f64 - 0.5
+            })
+            .collect();
+
+        let context = FusionContext::default();
+        let basis = WaveletBasis::Daubechies(2);
+        let tone_score = FlatnessWeightedFusion::score_basis(&basis, &tone, &context);
+        let noise_score = FlatnessWeightedFusion::score_basis(&basis, &noise, &context);
+        assert!(tone_score > noise_score, "{tone_score} <= {noise_score}");
+
+        // The tonal set dominates the fused coefficients
+        let fused = FlatnessWeightedFusion::fuse(
+            &[
+                decomposition(WaveletBasis::Haar, basis_transform(&tone, &basis)),
+                decomposition(WaveletBasis::Haar, basis_transform(&noise, &basis)),
+            ],
+            &context,
+        );
+        assert!(cosine_similarity(&fused.coefficients, &basis_transform(&tone, &basis)) > 0.8);
+    }
+
+    #[test]
+    fn orthogonal_bases_do_not_leak_energy_between_levels() {
+        let signal: Vec<f64> = (0..32).map(|i| ((i * 5) % 7) as f64 - 3.0).collect();
+        let energy: f64 = signal.iter().map(|x| x * x).sum();
+
+        assert!(level_energy_leakage(&signal, WaveletBasis::Haar, 3) < 1e-9 * energy);
+        assert!(level_energy_leakage(&signal, WaveletBasis::Daubechies(4), 3) < 1e-9 * energy);
+        assert!(level_energy_leakage(&signal, WaveletBasis::Biorthogonal(2, 2), 3) > 0.1 * energy);
+    }
+
+    #[test]
+    fn fused_decompositions_report_their_source_bases() {
+        let bases = vec![WaveletBasis::Haar, WaveletBasis::Daubechies(4)];
+        let engine = WaveletEngine::new(bases.clone(), EntropyWeightedFusion);
+        let fused = engine.fuse(&ramp(16), &FusionContext::default(), 1);
+        assert_eq!(fused.basis_sources, bases);
+
+        // Fusing fused results still reports the original bases, in input order
+        let other = decomposition(WaveletBasis::Biorthogonal(2, 2), vec![1.0; 16]);
+        let refused = EntropyWeightedFusion::fuse(&[fused, other], &FusionContext::default());
+        assert_eq!(
+            refused.basis_sources,
+            [WaveletBasis::Haar, WaveletBasis::Daubechies(4), WaveletBasis::Biorthogonal(2, 2)]
+        );
+    }
+
+
+    /// Uniform mean rescaled to a unit peak, so it changes even a single decomposition.
+    struct PeakNormalized;
+
+    impl WaveletFusionStrategy for PeakNormalized {
+        fn fuse(
+            decompositions: &[WaveletDecomposition],
+            _context: &FusionContext,
+        ) -> WaveletDecomposition {
+            let len = decompositions[0].coefficients.len();
+            let mut mean = vec![0.0; len];
+            for d in decompositions {
+                for (m, c) in mean.iter_mut().zip(&d.coefficients) {
+                    *m += c / decompositions.len() as f64;
+                }
+            }
+            let peak = mean.iter().fold(0.0_f64, |p, c| p.max(c.abs()));
+            let mut fused = decomposition(WaveletBasis::Custom("Peak".into()), mean);
+            fused.coefficients.iter_mut().for_each(|c| *c /= peak);
+            fused.basis_sources = WaveletDecomposition::fused_sources(decompositions);
+            fused
+        }
+
+        fn score_basis(_basis: &WaveletBasis, _signal: &[f64], _context: &FusionContext) -> f64 {
+            1.0
+        }
+    }
+
+    #[test]
+    fn chaining_differs_from_either_stage_alone() {
+        type Chain = ChainedFusion<EntropyWeightedFusion, PeakNormalized>;
+        let inputs = [
+            decomposition(WaveletBasis::Haar, vec![4.0, 0.0, 0.0]),
+            decomposition(WaveletBasis::Daubechies(4), vec![1.0, 1.0, 1.0]),
+        ];
+        let context = FusionContext::default();
+
+        let first = EntropyWeightedFusion::fuse(&inputs, &context).coefficients;
+        let second = PeakNormalized::fuse(&inputs, &context).coefficients;
+        let chained = Chain::fuse(&inputs, &context);
+
+        let peak = first.iter().fold(0.0_f64, |p, c| p.max(c.abs()));
+        let expected: Vec<f64> = first.iter().map(|c| c / peak).collect();
+        assert_close(&chained.coefficients, &expected, 1e-12);
+        assert!(chained.coefficients.iter().zip(&first).any(|(c, f)| (c - f).abs() > 1e-3));
+        assert!(chained.coefficients.iter().zip(&second).any(|(c, s)| (c - s).abs() > 1e-3));
+        assert_eq!(chained.basis, WaveletBasis::Custom("Peak".into()));
+        assert_eq!(chained.basis_sources, [WaveletBasis::Haar, WaveletBasis::Daubechies(4)]);
+    }
+
+    #[test]
+    fn weight_normalising_second_stage_only_relabels() {
+        let inputs = [
+            decomposition(WaveletBasis::Haar, vec![4.0, 0.0, 2.0]),
+            decomposition(WaveletBasis::Daubechies(4), vec![0.0, 2.0, 2.0]),
+        ];
+        let context = FusionContext::builder().with_resonance_profile(vec![3.0, 1.0, 1.0]).build();
+        let alone = ResonanceWeightedFusion::fuse(&inputs, &context);
+        type Chain = ChainedFusion<ResonanceWeightedFusion, EntropyWeightedFusion>;
+        let chained = Chain::fuse(&inputs, &context);
+        assert_close(&chained.coefficients, &alone.coefficients, 1e-12);
+        assert_eq!(chained.basis, WaveletBasis::Custom("EntropyFused".into()));
+        assert_eq!(chained.basis_sources, alone.basis_sources);
+    }
+
+
+    #[test]
+    fn multichannel_transforms_each_channel_and_rejects_ragged_input() {
+        let channels = vec![ramp(8), vec![1.0; 8], (0..8).map(|i| i as f64).collect()];
+        let coefficients = haar_transform_multichannel(&channels).unwrap();
+        assert_eq!(coefficients.len(), 3);
+        for (channel, coeffs) in channels.iter().zip(&coefficients) {
+            assert_close(coeffs, &haar_transform(channel), 1e-12);
+        }
+        assert!(haar_transform_multichannel(&[]).unwrap().is_empty());
+
+        let ragged = haar_transform_multichannel(&[ramp(8), ramp(8), ramp(6)]);
+        assert_eq!(ragged, Err(TransformError::InvalidLength.into()));
+    }
+
+
+    #[test]
+    fn layouts_round_trip_for_even_and_odd_lengths() {
+        let grouped = decomposition(WaveletBasis::Haar, vec![1.0, 2.0, 3.0, 10.0, 20.0, 30.0]);
+        let interleaved = grouped.to_interleaved();
+        assert_eq!(interleaved.layout, CoeffLayout::Interleaved);
+        assert_eq!(interleaved.coefficients, [1.0, 10.0, 2.0, 20.0, 3.0, 30.0]);
+        assert_eq!(interleaved.to_interleaved().coefficients, interleaved.coefficients);
+        let back = interleaved.to_grouped();
+        assert_eq!(back.layout, CoeffLayout::Grouped);
+        assert_eq!(back.coefficients, grouped.coefficients);
+        assert_eq!(back.basis_sources, grouped.basis_sources);
+
+        let odd = decomposition(WaveletBasis::Haar, vec![1.0, 2.0, 3.0, 10.0, 20.0]);
+        assert_eq!(odd.to_interleaved().coefficients, [1.0, 10.0, 2.0, 20.0, 3.0]);
+        assert_eq!(odd.to_interleaved().to_grouped().coefficients, odd.coefficients);
+    }
+
+    #[test]
+    fn reconstruction_reads_either_layout() {
+        let signal = ramp(16);
+        let grouped = decomposition(WaveletBasis::Haar, haar_transform(&signal));
+        let from_interleaved = grouped.to_interleaved().reconstruct().unwrap();
+        assert_close(&from_interleaved, &grouped.reconstruct().unwrap(), 1e-12);
+        assert_close(&from_interleaved, &signal, 1e-12);
+    }
+
+
+    #[test]
+    fn daubechies_filters_are_orthonormal_and_invert_exactly() {
+        let signal = ramp(32);
+        let energy: f64 = signal.iter().map(|x| x * x).sum();
+        for order in [2, 4, 6, 8] {
+            let lo = daubechies_filter(order).unwrap();
+            assert_eq!(lo.len(), 2 * order as usize);
+            assert!((lo.iter().sum::<f64>() - std::f64::consts::SQRT_2).abs() < 1e-12);
+            assert!((lo.iter().map(|h| h * h).sum::<f64>() - 1.0).abs() < 1e-12);
+
+            let coeffs = daubechies_decompose(&signal, order).unwrap();
+            assert_eq!((coeffs.approximation.len(), coeffs.detail.len()), (16, 16));
+            let bands = [coeffs.approximation.clone(), coeffs.detail.clone()].concat();
+            assert!((bands.iter().map(|c| c * c).sum::<f64>() - energy).abs() < 1e-9);
+            assert_eq!(daubechies_transform(&signal, order), bands);
+            assert_close(&daubechies_reconstruct(&coeffs, order).unwrap(), &signal, 1e-9);
+        }
+    }
+
+    #[test]
+    fn daubechies_details_vanish_on_polynomials_of_low_degree() {
+        // db4 has four vanishing moments, so a cubic leaves no detail away from the wrap
+        let cubic: Vec<f64> = (0..32).map(|i| (i as f64 * 0.1).powi(3) - i as f64).collect();
+        let detail = daubechies_decompose(&cubic, 4).unwrap().detail;
+        let interior = (32 - 8) / 2 + 1;
+        assert!(detail[..interior].iter().all(|d| d.abs() < 1e-9), "{detail:?}");
+        assert!(detail[interior..].iter().any(|d| d.abs() > 1e-3));
+    }
+
+    #[test]
+    fn daubechies_rejects_odd_lengths_and_unknown_orders() {
+        let odd = daubechies_decompose(&ramp(7), 4).unwrap_err();
+        assert_eq!(odd, TransformError::InvalidLength.into());
+        assert!(daubechies_transform(&ramp(7), 4).is_empty());
+        let unknown = daubechies_decompose(&ramp(8), 3).unwrap_err();
+        assert_eq!(unknown, TransformError::UnsupportedOrder(3).into());
+        let ragged = Coefficients { approximation: vec![1.0; 4], detail: vec![1.0; 3] };
+        let err = daubechies_reconstruct(&ragged, 2);
+        assert_eq!(err, Err(TransformError::ReconstructionFailed.into()));
+    }
+
+
+    /// Uniform samples in [-1, 1) from a 64-bit LCG, so each seed gives a fixed signal.
+    fn random_signal(len: usize, seed: u64) -> Vec<f64> {
+        let mut state = seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                (state >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+            })
+            .collect()
+    }
+
+    #[test]
+    fn exact_bases_round_trip_random_signals() {
+        let engine = WaveletEngine::new(
+            vec![
+                WaveletBasis::Haar,
+                WaveletBasis::Daubechies(2),
+                WaveletBasis::Daubechies(4),
+                WaveletBasis::Daubechies(6),
+                WaveletBasis::Daubechies(8),
+                WaveletBasis::Custom("reverse".into()),
+            ],
+            EntropyWeightedFusion,
+        );
+        for (seed, len) in [(1, 16), (2, 64), (3, 128), (4, 250), (5, 1024)] {
+            let signal = random_signal(len, seed);
+            for decomposition in engine.decompose_all(&signal, 1) {
+                let recovered = decomposition.reconstruct().unwrap();
+                assert_close(&recovered, &signal, 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn fusing_a_single_basis_is_reversible() {
+        let context = FusionContext::default();
+        for basis in [WaveletBasis::Haar, WaveletBasis::Daubechies(4)] {
+            let engine = WaveletEngine::new(vec![basis.clone(); 3], EntropyWeightedFusion);
+            for seed in 10..15 {
+                let signal = random_signal(96, seed);
+                let fused = engine.fuse(&signal, &context, 1);
+                assert_eq!(fused.basis_sources, vec![basis.clone(); 3]);
+                assert_close(&fused.reconstruct().unwrap(), &signal, 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn lossy_and_mixed_decompositions_report_what_they_can_recover() {
+        let signal = random_signal(40, 7);
+
+        // Biorthogonal inversion is least-squares: re-transforming gives back the coefficients
+        let coeffs = biorthogonal_transform(&signal, 3, 2);
+        let recovered = biorthogonal_reconstruct(&coeffs, 3, 2);
+        assert_eq!(recovered.len(), signal.len());
+        assert_close(&biorthogonal_transform(&recovered, 3, 2), &coeffs, 1e-9);
+
+        let pulse = decomposition(WaveletBasis::Custom("pulse".into()), signal.clone());
+        assert_eq!(pulse.reconstruct(), Err(TransformError::ReconstructionFailed.into()));
+
+        let engine = WaveletEngine::new(
+            vec![WaveletBasis::Haar, WaveletBasis::Daubechies(4)],
+            EntropyWeightedFusion,
+        );
+        let mixed = engine.fuse(&signal, &FusionContext::default(), 1);
+        assert_eq!(mixed.reconstruct(), Err(TransformError::ReconstructionFailed.into()));
+    }
+
+
+    #[test]
+    fn resonance_profiles_of_any_length_weight_every_coefficient() {
+        let decompositions = [
+            decomposition(WaveletBasis::Haar, vec![1.0, -2.0, 4.0, 2.0]),
+            decomposition(WaveletBasis::Daubechies(2), vec![3.0, 0.0, -4.0, 2.0]),
+        ];
+        let fuse = |profile: Vec<f64>| {
+            let context = FusionContext::builder().with_resonance_profile(profile).build();
+            ResonanceWeightedFusion::fuse(&decompositions, &context).coefficients
+        };
+        // Shorter profiles repeat their last value, longer ones are truncated
+        assert_close(&fuse(vec![1.0, 0.0]), &[2.0, 0.0, 0.0, 0.0], 1e-12);
+        assert_close(&fuse(vec![1.0, 1.0, 1.0, 1.0, 0.0]), &[2.0, -1.0, 0.0, 2.0], 1e-12);
+        assert_close(&fuse(vec![1.0, 0.0, 1.0, 0.0]), &[2.0, 0.0, 0.0, 0.0], 1e-12);
+    }
+
+    #[test]
+    fn resonance_scores_clamp_the_profile_to_the_coefficients() {
+        let identity = WaveletBasis::Custom("identity".into());
+        let signal = [1.0, -2.0, 3.0, 4.0];
+        let score = |profile: Option<Vec<f64>>| {
+            let context = FusionContext { resonance_profile: profile, ..Default::default() };
+            ResonanceWeightedFusion::score_basis(&identity, &signal, &context)
+        };
+        assert_eq!(score(Some(vec![2.0, 0.5])), 6.5);
+        assert_eq!(score(Some(vec![1.0, 1.0, 1.0, 2.0, 9.0])), 14.0);
+        assert_eq!(score(Some(vec![0.0, 1.0, 0.0, 1.0])), 6.0);
+        assert_eq!(score(Some(vec![])), 10.0);
+        assert_eq!(score(None), 10.0);
+    }
+
+
+    #[test]
+    fn entropy_fusion_aligns_a_mixed_basis_set() {
+        let engine = WaveletEngine::new(
+            vec![WaveletBasis::Haar, WaveletBasis::Daubechies(4), WaveletBasis::Biorthogonal(2, 2)],
+            EntropyWeightedFusion,
+        );
+        let signal = ramp(32);
+        let decompositions = engine.decompose_all(&signal, 1);
+        let lengths: Vec<usize> = decompositions.iter().map(|d| d.coefficients.len()).collect();
+        assert_eq!(lengths, [32, 32, 30]);
+
+        let fused = engine.fuse(&signal, &FusionContext::default(), 1);
+        assert_eq!(fused.coefficients.len(), 32);
+        assert_eq!(fused.basis_sources, engine.basis_set);
+
+        // Each decomposition is zero-padded band by band, weighted by its own entropy
+        let mut expected = vec![0.0; 32];
+        let mut total = 0.0;
+        for d in &decompositions {
+            let weight = 1.0 / (compute_entropy(&d.coefficients) + 1e-6);
+            total += weight;
+            for (e, c) in expected.iter_mut().zip(pad_bands(d, 32)) {
+                *e += weight * c;
+            }
+        }
+        expected.iter_mut().for_each(|e| *e /= total);
+        assert_close(&fused.coefficients, &expected, 1e-12);
+    }
+
+    #[test]
+    fn entropy_try_fuse_rejects_an_empty_set() {
+        let err = EntropyWeightedFusion::try_fuse(&[]).err();
+        assert_eq!(err, Some(TransformError::EmptyInput.into()));
+    }
+
+
+    #[test]
+    fn fusing_an_ensemble_of_identical_signals_matches_a_single_fuse() {
+        let context = FusionContext::builder().with_resonance_profile(vec![2.0, 1.0]).build();
+        let bases = vec![WaveletBasis::Haar, WaveletBasis::Daubechies(4)];
+        let signal = random_signal(64, 21);
+
+        let entropy = WaveletEngine::new(bases.clone(), EntropyWeightedFusion);
+        let single = entropy.fuse(&signal, &context, 1);
+        let ensemble = entropy.fuse_ensemble(&[&signal, &signal, &signal], &context, 1);
+        assert_close(&ensemble.coefficients, &single.coefficients, 1e-12);
+        assert_eq!(ensemble.basis_sources.len(), 3 * bases.len());
+        assert_eq!(ensemble.basis_sources[..2], single.basis_sources[..]);
+
+        let resonance = WaveletEngine::new(bases, ResonanceWeightedFusion);
+        let single = resonance.fuse(&signal, &context, 1);
+        let pair = resonance.fuse_ensemble(&[&signal, &signal], &context, 1);
+        assert_close(&pair.coefficients, &single.coefficients, 1e-12);
+        let one = resonance.fuse_ensemble(&[&signal], &context, 1);
+        assert_eq!(one.coefficients, single.coefficients);
+    }
+
+    #[test]
+    fn ensemble_fusion_averages_different_signals() {
+        let engine = WaveletEngine::new(vec![WaveletBasis::Haar], ResonanceWeightedFusion);
+        let context = FusionContext::default();
+        let (a, b) = (random_signal(32, 1), random_signal(32, 2));
+        let fused = engine.fuse_ensemble(&[&a, &b], &context, 1);
+        let (fa, fb) = (engine.fuse(&a, &context, 1), engine.fuse(&b, &context, 1));
+        let mean: Vec<f64> =
+            fa.coefficients.iter().zip(&fb.coefficients).map(|(x, y)| (x + y) / 2.0).collect();
+        assert_close(&fused.coefficients, &mean, 1e-12);
+        assert!(engine.fuse_ensemble(&[], &context, 1).coefficients.is_empty());
+    }
+
+
+    #[test]
+    #[should_panic(expected = "even-length")]
+    fn haar_transform_rejects_odd_lengths() {
+        haar_transform(&[1.0, 2.0, 3.0]);
+    }
+
+
+    #[test]
+    fn smoother_literal_fills_in_default_options() {
+        let signal = ramp(9);
+        let literal = WaveletTransformStruct { levels: 2, threshold: 0.1, ..Default::default() };
+        assert_eq!((literal.mode, literal.padding), (ThresholdMode::Hard, PaddingMode::ZeroPad));
+        assert_eq!(literal.smooth(&signal), WaveletTransformStruct::new(2, 0.1).smooth(&signal));
+        assert_close(&WaveletTransformStruct::default().smooth(&signal), &signal, 1e-12);
+    }
+}